@@ -0,0 +1,151 @@
+# Generator page
+
+app-title = Burner-Outlook-Generator
+stock-heading = Bestand
+email-type-outlook = Outlook
+email-type-hotmail = Hotmail
+email-type-random = Zufällig
+stock-allocated = Zugeteilt
+email-type-label = E-Mail-Typ wählen
+generate-button = E-Mail erzeugen
+generating = Wird erzeugt...
+retry-in = Erneut in { $remaining }
+cancel = Abbrechen
+bulk-button = Stapel ({ $count })
+bulk-retry-in = Stapel in { $remaining }
+bulk-progress = { $received }/{ $total }
+bulk-progress-status = E-Mails werden erzeugt, { $received } von { $total } empfangen
+bulk-progress-label = Fortschritt der Stapelerzeugung
+single-result-heading = Deine temporäre E-Mail
+bulk-result-heading = Deine temporären E-Mails
+
+captcha-required = Bitte löse das reCAPTCHA
+stock-load-failed = Bestand konnte nicht geladen werden
+generation-cancelled = Erzeugung abgebrochen
+generated-single = { $email } erzeugt
+generated-bulk =
+    { $count ->
+        [one] 1 E-Mail erzeugt
+       *[other] { $count } E-Mails erzeugt
+    }
+bulk-cancelled =
+    { $total ->
+        [one] Abgebrochen nach { $received } von { $total } E-Mail
+       *[other] Abgebrochen nach { $received } von { $total } E-Mails
+    }
+bulk-connection-lost =
+    { $total ->
+        [one] Verbindung verloren nach { $received } von { $total } E-Mail
+       *[other] Verbindung verloren nach { $received } von { $total } E-Mails
+    }
+bulk-timed-out =
+    { $total ->
+        [one] Zeitüberschreitung nach { $received } von { $total } E-Mail
+       *[other] Zeitüberschreitung nach { $received } von { $total } E-Mails
+    }
+bulk-partial =
+    { $total ->
+        [one] Gestoppt nach { $received } von { $total } E-Mail: { $error }
+       *[other] Gestoppt nach { $received } von { $total } E-Mails: { $error }
+    }
+bulk-empty = Keine E-Mails in der Antwort
+
+info-show = Wie funktioniert das?
+info-hide = Technische Details ausblenden
+info-heading = So funktioniert es: die wichtigsten Punkte
+info-intro = Dieses System nutzt ein paar tolle Techniken, um temporäre E-Mail-Adressen zu erzeugen, darunter:
+info-captcha = Eigene FunCaptcha-Umgehung mit 2000 Erkennungen pro Tag, freundlicherweise bereitgestellt von
+info-proxies = Rotierende Residential-Proxys
+info-api = Low-Level-Zugriffe auf die Microsoft-Auth-/Funcaptcha-API - das ist
+info-api-emphasis = SCHWER!
+info-purpose = Dieses Projekt ist vor allem eine praktische Übung darin, ein komplexes Reverse-Engineering-Projekt langfristig zu pflegen. Läuft der Dienst auch einige Monate nach seinem Start noch, gilt die Herausforderung für mich als gemeistert.
+info-note = Hinweis: Keine Sorge, wenn null zugeteilte Konten angezeigt werden! Das ist mir bekannt und ich arbeite bereits daran, mich an neue Änderungen anzupassen. Ich bin stolz darauf, diesen Dienst am Laufen zu halten, und spätestens am nächsten Tag läuft er wieder.
+
+footer-projects = Weitere coole Projekte findest du auf meinem
+footer-projects-end = !
+language-label = Sprache
+
+# Request errors
+
+request-timeout = Zeitüberschreitung der Anfrage
+request-cancelled = Anfrage abgebrochen
+invalid-request = Die Anfrage konnte nicht erstellt werden
+invalid-response = Ungültiges Antwortformat
+network-error = Netzwerkfehler
+server-status = Der Server antwortete mit { $status }
+rate-limited = Anfragelimit überschritten. Versuche es in { $remaining } erneut ({ $time }).
+
+error-captcha-invalid = Das reCAPTCHA ist abgelaufen oder wurde abgelehnt. Löse es erneut und versuche es noch einmal.
+error-out-of-stock-type = Gerade sind keine { $emailType }-Konten vorrätig. Wähle einen anderen E-Mail-Typ oder schau später wieder vorbei.
+error-out-of-stock = Gerade sind keine Konten vorrätig. Schau später wieder vorbei.
+error-invalid-email-type = Unbekannter E-Mail-Typ. Wähle Zufällig, Outlook oder Hotmail.
+error-account-not-found = Der Server kennt dieses Konto nicht und kann es daher nicht ersetzen.
+error-report-limit = Du hast in letzter Zeit zu viele Konten gemeldet. Versuche es später erneut.
+error-unavailable = Der Generator hat gerade Probleme. Versuche es in ein paar Minuten erneut.
+error-unknown = Etwas ist schiefgelaufen ({ $code })
+
+# Credentials
+
+field-email = E-Mail
+field-password = Passwort
+field-refresh-token = Refresh-Token
+field-client-id = Client-ID
+format-credentials = Benutzername:Passwort
+format-token = RefreshToken:ClientID
+format-full = Ganze Zeile
+show = Anzeigen
+hide = Verbergen
+show-field = { $label } anzeigen
+hide-field = { $label } verbergen
+copy = Kopieren
+copy-field = { $label } kopieren
+show-format = { $format } anzeigen
+
+# Clipboard
+
+copy-failed = Kopieren in die Zwischenablage fehlgeschlagen: { $error }
+copied = Kopiert
+copied-clears-in = Kopiert, die Zwischenablage wird in { $seconds } s geleert
+clipboard-clear-failed = Die Zwischenablage konnte nicht geleert werden. Leere sie selbst, falls sie noch ein Geheimnis enthält.
+clear-secrets-label = Kopierte Geheimnisse löschen
+clear-never = Nie
+clear-after = Nach { $seconds } s
+
+# Bulk table
+
+copy-format-label = Kopierformat
+show-secrets = Geheimnisse anzeigen
+hide-secrets = Geheimnisse verbergen
+select-all = Alle Konten auswählen
+select-account = { $email } auswählen
+actions = Aktionen
+copy-all = Alle kopieren
+copy-selected = Auswahl kopieren ({ $count })
+export-selected = Auswahl exportieren
+exported =
+    { $count ->
+        [one] 1 Konto exportiert
+       *[other] { $count } Konten exportiert
+    }
+export-failed = Konten konnten nicht exportiert werden
+
+# Reporting
+
+report-reason-label = Meldegrund
+report-reason-locked = Konto gesperrt
+report-reason-wrong-password = Falsches Passwort
+report-reason-token-invalid = Refresh-Token funktioniert nicht
+report-reason-other = Sonstiges
+report = Melden
+reporting = Wird gemeldet...
+report-failed = Meldung fehlgeschlagen
+report-replaced = { $email } ersetzt durch { $replacement }
+report-no-replacement = Meldung erhalten, kein Ersatz verfügbar
+
+# Settings
+
+theme-label = Design
+theme-light = Hell
+theme-dark = Dunkel
+theme-system = System
+close = Schließen
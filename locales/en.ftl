@@ -0,0 +1,151 @@
+# Generator page
+
+app-title = Burner Outlook Generator
+stock-heading = Stock
+email-type-outlook = Outlook
+email-type-hotmail = Hotmail
+email-type-random = Random
+stock-allocated = Allocated
+email-type-label = Select Email Type
+generate-button = Generate Email
+generating = Generating...
+retry-in = Try again in { $remaining }
+cancel = Cancel
+bulk-button = Bulk ({ $count })
+bulk-retry-in = Bulk in { $remaining }
+bulk-progress = { $received }/{ $total }
+bulk-progress-status = Generating emails, { $received } of { $total } received
+bulk-progress-label = Bulk generation progress
+single-result-heading = Your Temporary Email
+bulk-result-heading = Your Bulk Temporary Emails
+
+captcha-required = Please complete the reCAPTCHA
+stock-load-failed = Couldn't load stock counts
+generation-cancelled = Generation cancelled
+generated-single = Generated { $email }
+generated-bulk =
+    { $count ->
+        [one] Generated 1 email
+       *[other] Generated { $count } emails
+    }
+bulk-cancelled =
+    { $total ->
+        [one] Cancelled after { $received } of { $total } email
+       *[other] Cancelled after { $received } of { $total } emails
+    }
+bulk-connection-lost =
+    { $total ->
+        [one] Connection lost after { $received } of { $total } email
+       *[other] Connection lost after { $received } of { $total } emails
+    }
+bulk-timed-out =
+    { $total ->
+        [one] Timed out after { $received } of { $total } email
+       *[other] Timed out after { $received } of { $total } emails
+    }
+bulk-partial =
+    { $total ->
+        [one] Stopped after { $received } of { $total } email: { $error }
+       *[other] Stopped after { $received } of { $total } emails: { $error }
+    }
+bulk-empty = No emails in response
+
+info-show = How Does It Work?
+info-hide = Hide Technical Details
+info-heading = How It Works: Key Features
+info-intro = This system uses some awesome techniques to generate temporary email addresses, including:
+info-captcha = Custom FunCaptcha bypass, with 2000 raw recognitions/day provided graciously by
+info-proxies = Rotating residential proxies
+info-api = Low-level API interactions for Microsoft Auth/Funcaptcha - this stuff's
+info-api-emphasis = HARD!
+info-purpose = This project mainly serves as a practical exercise in maintaining a long-term, complex reverse engineering software project. As long as the service is still running more than a few months from it's creation, I consider the challenge a success.
+info-note = Note: If you see zero allocated accounts, don't worry! I'm definitely aware of it and actively working on patching any new changes. I take a lot of pride in keeping this service running and will be sure to have it up and running by the next day.
+
+footer-projects = For more cool projects, check out my
+footer-projects-end = !
+language-label = Language
+
+# Request errors
+
+request-timeout = Request timed out
+request-cancelled = Request cancelled
+invalid-request = Couldn't build the request
+invalid-response = Invalid response format
+network-error = Network error
+server-status = Server returned { $status }
+rate-limited = Rate limit exceeded. Try again in { $remaining } ({ $time }).
+
+error-captcha-invalid = The reCAPTCHA expired or was rejected. Complete it again and retry.
+error-out-of-stock-type = No { $emailType } accounts in stock right now. Pick another email type or check back later.
+error-out-of-stock = No accounts in stock right now. Check back later.
+error-invalid-email-type = Unknown email type. Pick Random, Outlook or Hotmail.
+error-account-not-found = The server doesn't know this account, so it can't be replaced.
+error-report-limit = You've reported too many accounts recently. Try again later.
+error-unavailable = The generator is having trouble right now. Try again in a few minutes.
+error-unknown = Something went wrong ({ $code })
+
+# Credentials
+
+field-email = Email
+field-password = Password
+field-refresh-token = Refresh Token
+field-client-id = Client ID
+format-credentials = Username:Password
+format-token = RefreshToken:ClientID
+format-full = Full line
+show = Show
+hide = Hide
+show-field = Show { $label }
+hide-field = Hide { $label }
+copy = Copy
+copy-field = Copy { $label }
+show-format = Show { $format }
+
+# Clipboard
+
+copy-failed = Couldn't copy to clipboard: { $error }
+copied = Copied
+copied-clears-in = Copied, clipboard clears in { $seconds }s
+clipboard-clear-failed = Couldn't clear the clipboard. Clear it yourself if it still holds a secret.
+clear-secrets-label = Clear copied secrets
+clear-never = Never
+clear-after = After { $seconds }s
+
+# Bulk table
+
+copy-format-label = Copy format
+show-secrets = Show secrets
+hide-secrets = Hide secrets
+select-all = Select all accounts
+select-account = Select { $email }
+actions = Actions
+copy-all = Copy All
+copy-selected = Copy Selected ({ $count })
+export-selected = Export Selected
+exported =
+    { $count ->
+        [one] Exported 1 account
+       *[other] Exported { $count } accounts
+    }
+export-failed = Couldn't export accounts
+
+# Reporting
+
+report-reason-label = Report reason
+report-reason-locked = Account locked
+report-reason-wrong-password = Wrong password
+report-reason-token-invalid = Refresh token not working
+report-reason-other = Other
+report = Report
+reporting = Reporting...
+report-failed = Report failed
+report-replaced = { $email } replaced with { $replacement }
+report-no-replacement = Report received, no replacement available
+
+# Settings
+
+theme-label = Theme
+theme-light = Light
+theme-dark = Dark
+theme-system = System
+close = Close
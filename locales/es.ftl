@@ -0,0 +1,151 @@
+# Generator page
+
+app-title = Generador de Burner Outlook
+stock-heading = Existencias
+email-type-outlook = Outlook
+email-type-hotmail = Hotmail
+email-type-random = Aleatorio
+stock-allocated = Asignadas
+email-type-label = Tipo de correo
+generate-button = Generar correo
+generating = Generando...
+retry-in = Reintenta en { $remaining }
+cancel = Cancelar
+bulk-button = Lote ({ $count })
+bulk-retry-in = Lote en { $remaining }
+bulk-progress = { $received }/{ $total }
+bulk-progress-status = Generando correos, { $received } de { $total } recibidos
+bulk-progress-label = Progreso de la generación en lote
+single-result-heading = Tu correo temporal
+bulk-result-heading = Tus correos temporales en lote
+
+captcha-required = Completa el reCAPTCHA
+stock-load-failed = No se pudieron cargar las existencias
+generation-cancelled = Generación cancelada
+generated-single = Generado { $email }
+generated-bulk =
+    { $count ->
+        [one] Se generó 1 correo
+       *[other] Se generaron { $count } correos
+    }
+bulk-cancelled =
+    { $total ->
+        [one] Cancelado tras { $received } de { $total } correo
+       *[other] Cancelado tras { $received } de { $total } correos
+    }
+bulk-connection-lost =
+    { $total ->
+        [one] Conexión perdida tras { $received } de { $total } correo
+       *[other] Conexión perdida tras { $received } de { $total } correos
+    }
+bulk-timed-out =
+    { $total ->
+        [one] Tiempo agotado tras { $received } de { $total } correo
+       *[other] Tiempo agotado tras { $received } de { $total } correos
+    }
+bulk-partial =
+    { $total ->
+        [one] Detenido tras { $received } de { $total } correo: { $error }
+       *[other] Detenido tras { $received } de { $total } correos: { $error }
+    }
+bulk-empty = La respuesta no contiene correos
+
+info-show = ¿Cómo funciona?
+info-hide = Ocultar detalles técnicos
+info-heading = Cómo funciona: características principales
+info-intro = Este sistema usa técnicas geniales para generar direcciones de correo temporales, entre ellas:
+info-captcha = Bypass propio de FunCaptcha, con 2000 reconocimientos diarios cedidos amablemente por
+info-proxies = Proxies residenciales rotativos
+info-api = Interacciones de bajo nivel con la API de Microsoft Auth/Funcaptcha: esto es
+info-api-emphasis = ¡DIFÍCIL!
+info-purpose = Este proyecto es sobre todo un ejercicio práctico de mantenimiento a largo plazo de un proyecto complejo de ingeniería inversa. Si el servicio sigue funcionando unos meses después de su creación, considero el reto superado.
+info-note = Nota: si ves cero cuentas asignadas, ¡no te preocupes! Ya lo sé y estoy trabajando en adaptarme a los últimos cambios. Me enorgullece mantener este servicio en marcha y volverá a funcionar al día siguiente.
+
+footer-projects = ¿Quieres ver más proyectos? Visita mi
+footer-projects-end = .
+language-label = Idioma
+
+# Request errors
+
+request-timeout = La solicitud superó el tiempo de espera
+request-cancelled = Solicitud cancelada
+invalid-request = No se pudo preparar la solicitud
+invalid-response = Formato de respuesta no válido
+network-error = Error de red
+server-status = El servidor respondió { $status }
+rate-limited = Límite de solicitudes superado. Reintenta en { $remaining } ({ $time }).
+
+error-captcha-invalid = El reCAPTCHA caducó o fue rechazado. Complétalo de nuevo y vuelve a intentarlo.
+error-out-of-stock-type = Ahora mismo no hay cuentas de { $emailType }. Elige otro tipo de correo o vuelve más tarde.
+error-out-of-stock = Ahora mismo no hay cuentas disponibles. Vuelve más tarde.
+error-invalid-email-type = Tipo de correo desconocido. Elige Aleatorio, Outlook o Hotmail.
+error-account-not-found = El servidor no conoce esta cuenta, así que no puede reemplazarla.
+error-report-limit = Has reportado demasiadas cuentas recientemente. Inténtalo más tarde.
+error-unavailable = El generador tiene problemas en este momento. Inténtalo de nuevo en unos minutos.
+error-unknown = Algo salió mal ({ $code })
+
+# Credentials
+
+field-email = Correo
+field-password = Contraseña
+field-refresh-token = Token de actualización
+field-client-id = ID de cliente
+format-credentials = Usuario:Contraseña
+format-token = RefreshToken:ClientID
+format-full = Línea completa
+show = Mostrar
+hide = Ocultar
+show-field = Mostrar { $label }
+hide-field = Ocultar { $label }
+copy = Copiar
+copy-field = Copiar { $label }
+show-format = Mostrar { $format }
+
+# Clipboard
+
+copy-failed = No se pudo copiar al portapapeles: { $error }
+copied = Copiado
+copied-clears-in = Copiado, el portapapeles se borrará en { $seconds } s
+clipboard-clear-failed = No se pudo borrar el portapapeles. Bórralo tú si aún contiene un secreto.
+clear-secrets-label = Borrar secretos copiados
+clear-never = Nunca
+clear-after = Tras { $seconds } s
+
+# Bulk table
+
+copy-format-label = Formato de copia
+show-secrets = Mostrar secretos
+hide-secrets = Ocultar secretos
+select-all = Seleccionar todas las cuentas
+select-account = Seleccionar { $email }
+actions = Acciones
+copy-all = Copiar todo
+copy-selected = Copiar selección ({ $count })
+export-selected = Exportar selección
+exported =
+    { $count ->
+        [one] Se exportó 1 cuenta
+       *[other] Se exportaron { $count } cuentas
+    }
+export-failed = No se pudieron exportar las cuentas
+
+# Reporting
+
+report-reason-label = Motivo del reporte
+report-reason-locked = Cuenta bloqueada
+report-reason-wrong-password = Contraseña incorrecta
+report-reason-token-invalid = El token de actualización no funciona
+report-reason-other = Otro
+report = Reportar
+reporting = Reportando...
+report-failed = No se pudo enviar el reporte
+report-replaced = { $email } reemplazada por { $replacement }
+report-no-replacement = Reporte recibido, no hay reemplazo disponible
+
+# Settings
+
+theme-label = Tema
+theme-light = Claro
+theme-dark = Oscuro
+theme-system = Sistema
+close = Cerrar
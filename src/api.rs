@@ -0,0 +1,145 @@
+use web_sys::Response;
+use serde::{Deserialize, Deserializer};
+
+use crate::cooldown;
+use crate::fetch::{self, Abort, FetchError};
+use crate::i18n::{t, t_args};
+
+// error envelope returned by the api, e.g.
+// {"code": "out_of_stock", "message": "...", "retry_after": null, "details": {...}}
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct ApiError {
+    pub code: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default, deserialize_with = "deserialize_retry_after")]
+    pub retry_after: Option<u64>,
+    #[serde(default)]
+    pub details: Option<serde_json::Value>,
+}
+
+// some handlers send fractional seconds, wait for the whole second
+fn deserialize_retry_after<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    Ok(Option::<f64>::deserialize(deserializer)?.map(|secs| secs.max(0.0).ceil() as u64))
+}
+
+// code given to plain "Error: ..." bodies from before the envelope existed
+const LEGACY_CODE: &str = "legacy";
+
+impl ApiError {
+    // json envelope first, then the legacy "Error:" prefix
+    pub fn parse(body: &str) -> Option<Self> {
+        let body = body.trim();
+        if body.starts_with('{') {
+            if let Ok(error) = serde_json::from_str::<ApiError>(body) {
+                return Some(error);
+            }
+        }
+        body.strip_prefix("Error:").map(|message| ApiError {
+            code: LEGACY_CODE.to_string(),
+            message: message.trim().to_string(),
+            retry_after: None,
+            details: None,
+        })
+    }
+
+    fn detail(&self, key: &str) -> Option<String> {
+        self.details
+            .as_ref()?
+            .get(key)?
+            .as_str()
+            .map(str::to_string)
+    }
+
+    // what to tell the user, and what they can do about it
+    pub fn user_message(&self) -> String {
+        match self.code.as_str() {
+            "captcha_invalid" => t("error-captcha-invalid"),
+            "rate_limited" => {
+                fetch::rate_limited_message(self.retry_after.unwrap_or(cooldown::DEFAULT_COOLDOWN_SECS))
+            }
+            "out_of_stock" => match self.detail("email_type") {
+                Some(email_type) => t_args("error-out-of-stock-type", [("emailType", email_type.into())]),
+                None => t("error-out-of-stock"),
+            },
+            "invalid_email_type" => t("error-invalid-email-type"),
+            "account_not_found" => t("error-account-not-found"),
+            "report_limit" => t("error-report-limit"),
+            "upstream_unavailable" | "internal" => t("error-unavailable"),
+            // the server's own wording, untranslated, beats a bare code
+            _ if !self.message.is_empty() => self.message.clone(),
+            code => t_args("error-unknown", [("code", code.into())]),
+        }
+    }
+
+    pub fn into_fetch_error(self) -> FetchError {
+        if self.code == "rate_limited" {
+            FetchError::RateLimited(self.retry_after.unwrap_or(cooldown::DEFAULT_COOLDOWN_SECS))
+        } else {
+            FetchError::Api(self)
+        }
+    }
+}
+
+// body of a text endpoint, with 429s and api errors turned into FetchErrors
+pub async fn read_text(response: &Response, abort: &Abort) -> Result<String, FetchError> {
+    if response.status() == 429 {
+        return Err(FetchError::RateLimited(cooldown::retry_after(response, abort).await));
+    }
+    let text = fetch::text(response, abort).await?;
+    if let Some(error) = ApiError::parse(&text) {
+        return Err(error.into_fetch_error());
+    }
+    if !response.ok() {
+        return Err(FetchError::Network(t_args("server-status", [("status", response.status().into())])));
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_full_envelope() {
+        let error = ApiError::parse(
+            r#"{"code": "out_of_stock", "message": "No stock", "retry_after": 30, "details": {"email_type": "hotmail"}}"#,
+        )
+        .unwrap();
+        assert_eq!(error.code, "out_of_stock");
+        assert_eq!(error.message, "No stock");
+        assert_eq!(error.retry_after, Some(30));
+        assert_eq!(error.detail("email_type").as_deref(), Some("hotmail"));
+    }
+
+    #[test]
+    fn parses_envelope_without_optional_fields() {
+        let error = ApiError::parse(r#"{"code": "internal"}"#).unwrap();
+        assert_eq!(error.code, "internal");
+        assert_eq!(error.message, "");
+        assert_eq!(error.retry_after, None);
+        assert!(error.details.is_none());
+    }
+
+    #[test]
+    fn rounds_fractional_retry_after_up() {
+        let error = ApiError::parse(r#"{"code": "rate_limited", "retry_after": 12.5}"#).unwrap();
+        assert_eq!(error.retry_after, Some(13));
+        let error = ApiError::parse(r#"{"code": "rate_limited", "retry_after": null}"#).unwrap();
+        assert_eq!(error.retry_after, None);
+    }
+
+    #[test]
+    fn falls_back_to_legacy_prefix() {
+        let error = ApiError::parse("Error: Invalid captcha\n").unwrap();
+        assert_eq!(error.code, LEGACY_CODE);
+        assert_eq!(error.message, "Invalid captcha");
+    }
+
+    #[test]
+    fn ignores_success_bodies() {
+        assert!(ApiError::parse("a@outlook.com:pass:token:client").is_none());
+        assert!(ApiError::parse(r#"{"email": "a@outlook.com"}"#).is_none());
+        assert!(ApiError::parse("").is_none());
+    }
+}
@@ -0,0 +1,333 @@
+use std::collections::HashSet;
+use yew::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, HtmlSelectElement, Url};
+use gloo_timers::callback::Timeout;
+
+use crate::EmailData;
+use crate::clipboard::use_clipboard;
+use crate::i18n::use_i18n;
+use crate::report::ReportButton;
+use crate::toast::use_toaster;
+
+#[derive(Clone, Copy, PartialEq)]
+enum CopyFormat {
+    Credentials,
+    Token,
+    Full,
+}
+
+const COPY_FORMATS: [CopyFormat; 3] = [CopyFormat::Credentials, CopyFormat::Token, CopyFormat::Full];
+
+impl CopyFormat {
+    fn value(self) -> &'static str {
+        match self {
+            CopyFormat::Credentials => "credentials",
+            CopyFormat::Token => "token",
+            CopyFormat::Full => "full",
+        }
+    }
+
+    fn message_id(self) -> &'static str {
+        match self {
+            CopyFormat::Credentials => "format-credentials",
+            CopyFormat::Token => "format-token",
+            CopyFormat::Full => "format-full",
+        }
+    }
+
+    fn from_value(value: &str) -> Self {
+        COPY_FORMATS
+            .into_iter()
+            .find(|format| format.value() == value)
+            .unwrap_or(CopyFormat::Credentials)
+    }
+
+    fn line(self, account: &EmailData) -> String {
+        match self {
+            CopyFormat::Credentials => format!("{}:{}", account.email, account.password),
+            CopyFormat::Token => format!("{}:{}", account.refresh_token, account.client_id),
+            CopyFormat::Full => format!(
+                "{}:{}:{}:{}",
+                account.email, account.password, account.refresh_token, account.client_id
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum DomainSort {
+    Original,
+    Ascending,
+    Descending,
+}
+
+impl DomainSort {
+    fn next(self) -> Self {
+        match self {
+            DomainSort::Original => DomainSort::Ascending,
+            DomainSort::Ascending => DomainSort::Descending,
+            DomainSort::Descending => DomainSort::Original,
+        }
+    }
+
+    fn aria(self) -> &'static str {
+        match self {
+            DomainSort::Original => "none",
+            DomainSort::Ascending => "ascending",
+            DomainSort::Descending => "descending",
+        }
+    }
+}
+
+fn domain(account: &EmailData) -> &str {
+    account.email.rsplit('@').next().unwrap_or_default()
+}
+
+fn mask(secret: &str) -> String {
+    "•".repeat(secret.chars().count().min(12))
+}
+
+// hands the text to the browser as a file download
+fn download_text(filename: &str, text: &str) -> Result<(), JsValue> {
+    let options = BlobPropertyBag::new();
+    options.set_type("text/plain");
+    let parts = js_sys::Array::of1(&JsValue::from_str(text));
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let anchor = document.create_element("a")?.dyn_into::<HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+    // revoking straight away cancels the download in some browsers (Safari, older Firefox)
+    Timeout::new(1000, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BulkTableProps {
+    pub emails: Vec<EmailData>,
+    // called with the reported email and its replacement
+    pub on_replacement: Callback<(String, EmailData)>,
+}
+
+#[function_component(BulkTable)]
+pub fn bulk_table(props: &BulkTableProps) -> Html {
+    let clipboard = use_clipboard();
+    let toaster = use_toaster();
+    let i18n = use_i18n();
+    let copy_format = use_state(|| CopyFormat::Credentials);
+    let show_secrets = use_state(|| false);
+    let sort = use_state(|| DomainSort::Original);
+    // selection is keyed by email so replaced accounts drop out of it
+    let selected = use_state(HashSet::<String>::new);
+
+    let mut rows: Vec<&EmailData> = props.emails.iter().collect();
+    match *sort {
+        DomainSort::Original => {}
+        DomainSort::Ascending => rows.sort_by(|a, b| domain(a).cmp(domain(b))),
+        DomainSort::Descending => rows.sort_by(|a, b| domain(b).cmp(domain(a))),
+    }
+
+    let selected_accounts: Vec<EmailData> = rows
+        .iter()
+        .filter(|account| selected.contains(&account.email))
+        .map(|account| (*account).clone())
+        .collect();
+    let all_selected = !rows.is_empty() && selected_accounts.len() == rows.len();
+
+    let on_format_change = {
+        let copy_format = copy_format.clone();
+        Callback::from(move |e: Event| {
+            if let Some(target) = e.target_dyn_into::<HtmlSelectElement>() {
+                copy_format.set(CopyFormat::from_value(&target.value()));
+            }
+        })
+    };
+
+    let toggle_secrets = {
+        let show_secrets = show_secrets.clone();
+        Callback::from(move |_| show_secrets.set(!*show_secrets))
+    };
+
+    let toggle_sort = {
+        let sort = sort.clone();
+        Callback::from(move |_| sort.set(sort.next()))
+    };
+
+    let toggle_all = {
+        let selected = selected.clone();
+        let emails: HashSet<String> = props.emails.iter().map(|a| a.email.clone()).collect();
+        Callback::from(move |_| {
+            if all_selected {
+                selected.set(HashSet::new());
+            } else {
+                selected.set(emails.clone());
+            }
+        })
+    };
+
+    let copy_lines = {
+        let clipboard = clipboard.clone();
+        let copy_format = copy_format.clone();
+        move |accounts: &[EmailData]| {
+            let text = accounts
+                .iter()
+                .map(|account| copy_format.line(account))
+                .collect::<Vec<String>>()
+                .join("\n");
+            clipboard.copy(text, true);
+        }
+    };
+
+    let copy_selected = {
+        let copy_lines = copy_lines.clone();
+        let accounts = selected_accounts.clone();
+        Callback::from(move |_| copy_lines(&accounts))
+    };
+
+    let copy_all = {
+        let accounts: Vec<EmailData> = rows.iter().map(|account| (*account).clone()).collect();
+        Callback::from(move |_| copy_lines(&accounts))
+    };
+
+    let export_selected = {
+        let copy_format = copy_format.clone();
+        let accounts = selected_accounts.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let text = accounts
+                .iter()
+                .map(|account| copy_format.line(account))
+                .collect::<Vec<String>>()
+                .join("\n");
+            match download_text("accounts.txt", &text) {
+                Ok(()) => toaster.success(i18n.t_args("exported", [("count", accounts.len().into())])),
+                Err(_) => toaster.error(i18n.t("export-failed")),
+            }
+        })
+    };
+
+    let secret_cell = |value: &str| {
+        if *show_secrets {
+            value.to_string()
+        } else {
+            mask(value)
+        }
+    };
+
+    html! {
+        <div>
+            <div class="d-flex flex-wrap align-items-center gap-2 mb-2">
+                <select class="form-select form-select-sm w-auto" aria-label={i18n.t("copy-format-label")} onchange={on_format_change}>
+                    {COPY_FORMATS.iter().map(|format| {
+                        html! {
+                            <option value={format.value()} selected={*copy_format == *format}>{i18n.t(format.message_id())}</option>
+                        }
+                    }).collect::<Html>()}
+                </select>
+                <button class="btn btn-secondary btn-sm" aria-pressed={show_secrets.to_string()} onclick={toggle_secrets}>
+                    {i18n.t(if *show_secrets { "hide-secrets" } else { "show-secrets" })}
+                </button>
+            </div>
+            <div class="table-responsive">
+                <table class="table table-sm table-hover align-middle mb-2">
+                    <thead>
+                        <tr>
+                            <th scope="col">
+                                <input
+                                    type="checkbox"
+                                    class="form-check-input"
+                                    aria-label={i18n.t("select-all")}
+                                    checked={all_selected}
+                                    onchange={toggle_all}
+                                />
+                            </th>
+                            <th scope="col" aria-sort={sort.aria()}>
+                                <button class="btn btn-link btn-sm p-0 text-reset fw-bold text-decoration-none" onclick={toggle_sort}>
+                                    {i18n.t("field-email")}
+                                    {match *sort {
+                                        DomainSort::Original => "",
+                                        DomainSort::Ascending => " ▲",
+                                        DomainSort::Descending => " ▼",
+                                    }}
+                                </button>
+                            </th>
+                            <th scope="col">{i18n.t("field-password")}</th>
+                            <th scope="col">{i18n.t("field-refresh-token")}</th>
+                            <th scope="col">{i18n.t("field-client-id")}</th>
+                            <th scope="col"><span class="visually-hidden">{i18n.t("actions")}</span></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {rows.iter().map(|account| {
+                            let is_selected = selected.contains(&account.email);
+                            let toggle_row = {
+                                let selected = selected.clone();
+                                let email = account.email.clone();
+                                Callback::from(move |_| {
+                                    let mut next = (*selected).clone();
+                                    if !next.remove(&email) {
+                                        next.insert(email.clone());
+                                    }
+                                    selected.set(next);
+                                })
+                            };
+                            let copy_row = {
+                                let clipboard = clipboard.clone();
+                                let line = copy_format.line(account);
+                                Callback::from(move |_| clipboard.copy(line.clone(), true))
+                            };
+                            let on_replacement = {
+                                let email = account.email.clone();
+                                props.on_replacement.reform(move |data: EmailData| (email.clone(), data))
+                            };
+                            html! {
+                                <tr key={account.email.clone()} class={classes!(is_selected.then_some("table-active"))}>
+                                    <td>
+                                        <input
+                                            type="checkbox"
+                                            class="form-check-input"
+                                            aria-label={i18n.t_args("select-account", [("email", account.email.as_str().into())])}
+                                            checked={is_selected}
+                                            onchange={toggle_row}
+                                        />
+                                    </td>
+                                    <td class="text-nowrap">{account.email.clone()}</td>
+                                    <td class="font-monospace">{secret_cell(&account.password)}</td>
+                                    <td class="font-monospace text-truncate bulk-token">{secret_cell(&account.refresh_token)}</td>
+                                    <td class="font-monospace text-truncate bulk-token">{account.client_id.clone()}</td>
+                                    <td class="text-nowrap">
+                                        <button class="btn btn-outline-secondary btn-sm" aria-label={i18n.t_args("copy-field", [("label", account.email.as_str().into())])} onclick={copy_row}>
+                                            {i18n.t("copy")}
+                                        </button>
+                                        <ReportButton email={account.email.clone()} on_replacement={on_replacement} />
+                                    </td>
+                                </tr>
+                            }
+                        }).collect::<Html>()}
+                    </tbody>
+                </table>
+            </div>
+            <div class="d-flex flex-wrap gap-2">
+                <button class="btn btn-outline-secondary" onclick={copy_all}>
+                    {i18n.t("copy-all")}
+                </button>
+                <button class="btn btn-outline-secondary" onclick={copy_selected} disabled={selected_accounts.is_empty()}>
+                    {i18n.t_args("copy-selected", [("count", selected_accounts.len().into())])}
+                </button>
+                <button class="btn btn-outline-secondary" onclick={export_selected} disabled={selected_accounts.is_empty()}>
+                    {i18n.t("export-selected")}
+                </button>
+            </div>
+        </div>
+    }
+}
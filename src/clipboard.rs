@@ -0,0 +1,242 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{HtmlDocument, HtmlSelectElement, HtmlTextAreaElement};
+use gloo_timers::callback::Timeout;
+
+use crate::i18n::{t, t_args, use_i18n};
+use crate::local_storage;
+use crate::toast::{use_toaster, Toaster};
+
+// localStorage key for the auto-clear delay, 0 means never clear
+const CLEAR_AFTER_KEY: &str = "clipboard_clear_after";
+const CLEAR_AFTER_OPTIONS: [u32; 4] = [0, 15, 30, 60];
+
+fn clear_after_secs() -> u32 {
+    local_storage()
+        .and_then(|s| s.get_item(CLEAR_AFTER_KEY).ok().flatten())
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
+}
+
+// navigator.clipboard is missing outside secure contexts and in older browsers
+fn async_clipboard() -> Option<web_sys::Clipboard> {
+    let navigator = web_sys::window()?.navigator();
+    let clipboard = js_sys::Reflect::get(&navigator, &JsValue::from_str("clipboard")).ok()?;
+    if clipboard.is_undefined() || clipboard.is_null() {
+        None
+    } else {
+        Some(clipboard.unchecked_into())
+    }
+}
+
+// selection based copy for browsers without the async clipboard api
+fn copy_with_selection(text: &str) -> Result<(), String> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("no document")?;
+    let body = document.body().ok_or("no document body")?;
+    let textarea = document
+        .create_element("textarea")
+        .map_err(|_| "couldn't create textarea")?
+        .dyn_into::<HtmlTextAreaElement>()
+        .map_err(|_| "couldn't create textarea")?;
+    textarea.set_value(text);
+    let _ = textarea.set_attribute("readonly", "");
+    let _ = textarea.set_attribute("style", "position: fixed; top: -1000px; opacity: 0;");
+    body.append_child(&textarea).map_err(|_| "couldn't attach textarea")?;
+    textarea.select();
+    let copied = document
+        .dyn_into::<HtmlDocument>()
+        .ok()
+        .and_then(|d| d.exec_command("copy").ok())
+        .unwrap_or(false);
+    textarea.remove();
+    if copied {
+        Ok(())
+    } else {
+        Err("copy command was rejected".to_string())
+    }
+}
+
+async fn write_text(text: &str) -> Result<(), String> {
+    if let Some(clipboard) = async_clipboard() {
+        if JsFuture::from(clipboard.write_text(text)).await.is_ok() {
+            return Ok(());
+        }
+    }
+    copy_with_selection(text)
+}
+
+// only true if reading was allowed before, so checking never brings up a prompt
+async fn read_permission_granted() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    let query = || -> Result<js_sys::Promise, JsValue> {
+        let permissions = js_sys::Reflect::get(&window.navigator(), &JsValue::from_str("permissions"))?;
+        let query_fn = js_sys::Reflect::get(&permissions, &JsValue::from_str("query"))?
+            .dyn_into::<js_sys::Function>()?;
+        let descriptor = js_sys::Object::new();
+        js_sys::Reflect::set(&descriptor, &JsValue::from_str("name"), &JsValue::from_str("clipboard-read"))?;
+        query_fn.call1(&permissions, &descriptor)?.dyn_into::<js_sys::Promise>()
+    };
+    let Ok(promise) = query() else {
+        return false;
+    };
+    // browsers that don't know the permission reject the query
+    let Ok(status) = JsFuture::from(promise).await else {
+        return false;
+    };
+    js_sys::Reflect::get(&status, &JsValue::from_str("state"))
+        .ok()
+        .and_then(|state| state.as_string())
+        .as_deref()
+        == Some("granted")
+}
+
+// clears the clipboard unless it's been overwritten since. without read access
+// we clear anyway since the user asked for it. only the async api can do this,
+// execCommand needs a user gesture, and it fails while the page isn't focused
+async fn clear_if_unchanged(copied: &str) -> Result<(), ()> {
+    let clipboard = async_clipboard().ok_or(())?;
+    if read_permission_granted().await {
+        if let Ok(current) = JsFuture::from(clipboard.read_text()).await {
+            if current.as_string().as_deref() != Some(copied) {
+                return Ok(());
+            }
+        }
+    }
+    JsFuture::from(clipboard.write_text("")).await.map(|_| ()).map_err(|_| ())
+}
+
+// runs f once the page has focus again
+fn on_next_focus(f: impl FnOnce() + 'static) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(document) = window.document() else {
+        return;
+    };
+    let listener = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
+    let mut f = Some(f);
+    let callback = {
+        let listener = listener.clone();
+        let window = window.clone();
+        let document = document.clone();
+        Closure::wrap(Box::new(move || {
+            if !document.has_focus().unwrap_or(false) {
+                return;
+            }
+            if let Some(closure) = listener.borrow_mut().take() {
+                let _ = window.remove_event_listener_with_callback("focus", closure.as_ref().unchecked_ref());
+                let _ = document.remove_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref());
+                // can't drop a closure while it's running, let it go on the next tick
+                Timeout::new(0, move || drop(closure)).forget();
+            }
+            if let Some(f) = f.take() {
+                f();
+            }
+        }) as Box<dyn FnMut()>)
+    };
+    let _ = window.add_event_listener_with_callback("focus", callback.as_ref().unchecked_ref());
+    let _ = document.add_event_listener_with_callback("visibilitychange", callback.as_ref().unchecked_ref());
+    *listener.borrow_mut() = Some(callback);
+}
+
+// the timer usually fires while the user is pasting in another window, so a
+// failed clear is retried when they come back, and reported if it fails again
+async fn auto_clear(copied: String, toaster: Toaster) {
+    if clear_if_unchanged(&copied).await.is_ok() {
+        return;
+    }
+    let has_focus = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.has_focus().ok())
+        .unwrap_or(false);
+    if has_focus {
+        toaster.warning(t("clipboard-clear-failed"));
+        return;
+    }
+    on_next_focus(move || {
+        spawn_local(async move {
+            if clear_if_unchanged(&copied).await.is_err() {
+                toaster.warning(t("clipboard-clear-failed"));
+            }
+        });
+    });
+}
+
+#[derive(Clone, PartialEq)]
+pub struct ClipboardService {
+    toaster: Toaster,
+}
+
+impl ClipboardService {
+    // secret copies (passwords, refresh tokens) honour the auto-clear setting
+    pub fn copy(&self, text: String, secret: bool) {
+        let toaster = self.toaster.clone();
+        spawn_local(async move {
+            if let Err(e) = write_text(&text).await {
+                toaster.error(t_args("copy-failed", [("error", e.into())]));
+                return;
+            }
+
+            let clear_after = if secret { clear_after_secs() } else { 0 };
+            if clear_after == 0 {
+                toaster.success(t("copied"));
+                return;
+            }
+
+            toaster.success(t_args("copied-clears-in", [("seconds", clear_after.into())]));
+            Timeout::new(clear_after * 1000, move || spawn_local(auto_clear(text, toaster)))
+            .forget();
+        });
+    }
+}
+
+pub fn use_clipboard() -> ClipboardService {
+    ClipboardService {
+        toaster: use_toaster(),
+    }
+}
+
+// picker for how long copied secrets stay on the clipboard
+#[function_component(ClipboardSettings)]
+pub fn clipboard_settings() -> Html {
+    let clear_after = use_state(clear_after_secs);
+    let i18n = use_i18n();
+
+    let onchange = {
+        let clear_after = clear_after.clone();
+        Callback::from(move |e: Event| {
+            if let Some(target) = e.target_dyn_into::<HtmlSelectElement>() {
+                let secs = target.value().parse().unwrap_or(0);
+                if let Some(storage) = local_storage() {
+                    let _ = storage.set_item(CLEAR_AFTER_KEY, &secs.to_string());
+                }
+                clear_after.set(secs);
+            }
+        })
+    };
+
+    html! {
+        <div class="d-flex align-items-center justify-content-end mt-2">
+            <label for="clipboard-clear" class="form-label small text-muted mb-0 me-2">
+                {i18n.t("clear-secrets-label")}
+            </label>
+            <select id="clipboard-clear" class="form-select form-select-sm w-auto" onchange={onchange}>
+                {CLEAR_AFTER_OPTIONS.iter().map(|secs| {
+                    html! {
+                        <option value={secs.to_string()} selected={*clear_after == *secs}>
+                            {if *secs == 0 { i18n.t("clear-never") } else { i18n.t_args("clear-after", [("seconds", (*secs).into())]) }}
+                        </option>
+                    }
+                }).collect::<Html>()}
+            </select>
+        </div>
+    }
+}
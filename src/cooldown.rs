@@ -0,0 +1,142 @@
+use yew::prelude::*;
+use web_sys::Response;
+use gloo_timers::callback::Timeout;
+
+use crate::api::ApiError;
+use crate::fetch::{self, Abort};
+use crate::local_storage;
+use crate::sync::{self, use_sync, SyncMessage};
+
+// used when a 429 comes back without saying how long to wait
+pub const DEFAULT_COOLDOWN_SECS: u64 = 24 * 60 * 60;
+
+fn storage_key(endpoint: &str) -> String {
+    format!("cooldown_until_{}", endpoint)
+}
+
+fn stored_until(endpoint: &str) -> f64 {
+    local_storage()
+        .and_then(|s| s.get_item(&storage_key(endpoint)).ok().flatten())
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0.0)
+}
+
+// Retry-After is either delay-seconds or an http date
+fn parse_retry_after(value: &str) -> Option<u64> {
+    if let Ok(secs) = value.trim().parse::<u64>() {
+        return Some(secs);
+    }
+    let date = js_sys::Date::parse(value.trim());
+    if date.is_nan() {
+        return None;
+    }
+    Some(((date - js_sys::Date::now()) / 1000.0).ceil().max(0.0) as u64)
+}
+
+// how long the server wants us to back off, from the header or the error body
+pub async fn retry_after(response: &Response, abort: &Abort) -> u64 {
+    if let Some(secs) = response
+        .headers()
+        .get("Retry-After")
+        .ok()
+        .flatten()
+        .and_then(|value| parse_retry_after(&value))
+    {
+        return secs;
+    }
+    fetch::json::<ApiError>(response, abort)
+        .await
+        .ok()
+        .and_then(|error| error.retry_after)
+        .unwrap_or(DEFAULT_COOLDOWN_SECS)
+}
+
+pub fn format_remaining(secs: u64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+// rate-limit cooldown for one endpoint, kept in localStorage across reloads
+#[derive(Clone)]
+pub struct Cooldown {
+    endpoint: &'static str,
+    until: UseStateHandle<f64>,
+    now: UseStateHandle<f64>,
+}
+
+impl Cooldown {
+    pub fn remaining_secs(&self) -> u64 {
+        ((*self.until - *self.now) / 1000.0).ceil().max(0.0) as u64
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.remaining_secs() > 0
+    }
+
+    pub fn start(&self, secs: u64) {
+        let now = js_sys::Date::now();
+        let until = now + secs as f64 * 1000.0;
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(&storage_key(self.endpoint), &until.to_string());
+        }
+        self.now.set(now);
+        self.until.set(until);
+        sync::broadcast(&SyncMessage::Cooldown {
+            endpoint: self.endpoint.to_string(),
+            until,
+        });
+    }
+}
+
+pub fn use_cooldown(endpoint: &'static str) -> Cooldown {
+    let until = use_state(|| stored_until(endpoint));
+    let now = use_state(js_sys::Date::now);
+
+    // tick once a second until the cooldown runs out
+    {
+        let now_handle = now.clone();
+        use_effect_with_deps(
+            move |(until, now)| {
+                let timeout = (now < until).then(|| {
+                    Timeout::new(1000, move || now_handle.set(js_sys::Date::now()))
+                });
+                move || drop(timeout)
+            },
+            (*until, *now),
+        );
+    }
+
+    // cooldowns hit in other tabs apply here too
+    {
+        let until = until.clone();
+        let now = now.clone();
+        use_sync(move |message| {
+            if let SyncMessage::Cooldown { endpoint: other, until: other_until } = message {
+                if other == endpoint {
+                    now.set(js_sys::Date::now());
+                    until.set(other_until);
+                }
+            }
+        });
+    }
+
+    Cooldown { endpoint, until, now }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_minutes_and_hours() {
+        assert_eq!(format_remaining(0), "0:00");
+        assert_eq!(format_remaining(59), "0:59");
+        assert_eq!(format_remaining(61), "1:01");
+        assert_eq!(format_remaining(3600), "1:00:00");
+        assert_eq!(format_remaining(DEFAULT_COOLDOWN_SECS - 1), "23:59:59");
+    }
+}
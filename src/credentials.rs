@@ -0,0 +1,109 @@
+use yew::prelude::*;
+
+use crate::EmailData;
+use crate::clipboard::use_clipboard;
+use crate::i18n::use_i18n;
+
+#[derive(Properties, PartialEq)]
+pub struct CredentialFieldProps {
+    pub label: String,
+    pub value: String,
+    // secrets start masked and clear the clipboard after copying if enabled
+    #[prop_or_default]
+    pub secret: bool,
+}
+
+#[function_component(CredentialField)]
+pub fn credential_field(props: &CredentialFieldProps) -> Html {
+    let revealed = use_state(|| false);
+    let clipboard = use_clipboard();
+    let i18n = use_i18n();
+
+    let toggle_reveal = {
+        let revealed = revealed.clone();
+        Callback::from(move |_| revealed.set(!*revealed))
+    };
+
+    let copy = {
+        let value = props.value.clone();
+        let secret = props.secret;
+        Callback::from(move |_| clipboard.copy(value.clone(), secret))
+    };
+
+    let masked = props.secret && !*revealed;
+
+    html! {
+        <div class="mb-2">
+            <div class="small text-muted mb-1">{props.label.clone()}</div>
+            <div class="input-group input-group-sm">
+                <input
+                    type={if masked { "password" } else { "text" }}
+                    class="form-control font-monospace"
+                    aria-label={props.label.clone()}
+                    value={props.value.clone()}
+                    readonly=true
+                />
+                if props.secret {
+                    <button
+                        class="btn btn-outline-secondary"
+                        aria-label={i18n.t_args(if masked { "show-field" } else { "hide-field" }, [("label", props.label.as_str().into())])}
+                        aria-pressed={(!masked).to_string()}
+                        onclick={toggle_reveal}
+                    >
+                        {i18n.t(if masked { "show" } else { "hide" })}
+                    </button>
+                }
+                <button class="btn btn-outline-secondary" aria-label={i18n.t_args("copy-field", [("label", props.label.as_str().into())])} onclick={copy}>
+                    {i18n.t("copy")}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CredentialCardProps {
+    pub account: EmailData,
+}
+
+// one line per field, plus the joined combo line for pasting into tools
+#[function_component(CredentialCard)]
+pub fn credential_card(props: &CredentialCardProps) -> Html {
+    let show_credentials = use_state(|| true);
+    let i18n = use_i18n();
+
+    let toggle_view = {
+        let show_credentials = show_credentials.clone();
+        Callback::from(move |_| show_credentials.set(!*show_credentials))
+    };
+
+    let account = &props.account;
+    let (shown_format, other_format) = if *show_credentials {
+        (i18n.t("format-credentials"), i18n.t("format-token"))
+    } else {
+        (i18n.t("format-token"), i18n.t("format-credentials"))
+    };
+    let combo = if *show_credentials {
+        format!("{}:{}", account.email, account.password)
+    } else {
+        format!("{}:{}", account.refresh_token, account.client_id)
+    };
+
+    html! {
+        <div class="card card-body mb-2">
+            <CredentialField label={i18n.t("field-email")} value={account.email.clone()} />
+            <CredentialField label={i18n.t("field-password")} value={account.password.clone()} secret=true />
+            <CredentialField label={i18n.t("field-refresh-token")} value={account.refresh_token.clone()} secret=true />
+            <CredentialField label={i18n.t("field-client-id")} value={account.client_id.clone()} />
+            <hr class="my-2" />
+            <button class="btn btn-secondary btn-sm mb-2 align-self-start" onclick={toggle_view}>
+                {i18n.t_args("show-format", [("format", other_format.into())])}
+            </button>
+            <CredentialField
+                label={shown_format}
+                value={combo}
+                secret=true
+            />
+        </div>
+    }
+}
@@ -0,0 +1,253 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use yew::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortController, ReadableStreamDefaultReader, RequestInit, Response};
+use gloo_timers::callback::Timeout;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::api::ApiError;
+use crate::cooldown::format_remaining;
+use crate::i18n::{self, t, t_args};
+
+// default time before an api request is given up on
+pub const API_TIMEOUT_MS: u32 = 30_000;
+
+#[derive(Clone, Copy, PartialEq)]
+enum AbortReason {
+    Timeout,
+    Cancelled,
+    Unmounted,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum FetchError {
+    Timeout,
+    // the user hit cancel
+    Cancelled,
+    // the component that started the request is gone - don't touch its state
+    Unmounted,
+    // the request body couldn't be encoded, nothing was sent
+    InvalidRequest,
+    InvalidResponse,
+    // 429, with the seconds to wait before trying again
+    RateLimited(u64),
+    Api(ApiError),
+    Network(String),
+}
+
+impl FetchError {
+    pub fn message(&self) -> String {
+        match self {
+            FetchError::Timeout => t("request-timeout"),
+            FetchError::Cancelled => t("request-cancelled"),
+            FetchError::Unmounted => String::new(),
+            FetchError::InvalidRequest => t("invalid-request"),
+            FetchError::InvalidResponse => t("invalid-response"),
+            FetchError::RateLimited(secs) => rate_limited_message(*secs),
+            FetchError::Api(error) => error.user_message(),
+            FetchError::Network(message) => message.clone(),
+        }
+    }
+}
+
+// how long until the limit lifts, and when that is on the user's clock
+pub fn rate_limited_message(secs: u64) -> String {
+    let until = js_sys::Date::now() + secs as f64 * 1000.0;
+    t_args(
+        "rate-limited",
+        [
+            ("remaining", format_remaining(secs).into()),
+            ("time", i18n::format_date_time(until).into()),
+        ],
+    )
+}
+
+// abort signal for one request, fired by cancel(), unmount or its timeout
+#[derive(Clone)]
+pub struct Abort {
+    controller: AbortController,
+    reason: Rc<Cell<Option<AbortReason>>>,
+    // dropping the last clone clears the timer
+    _timeout: Rc<Timeout>,
+}
+
+impl Abort {
+    pub fn with_timeout(timeout_ms: u32) -> Self {
+        let controller = AbortController::new().expect("AbortController is not supported");
+        let reason = Rc::new(Cell::new(None));
+        let timeout = {
+            let controller = controller.clone();
+            let reason = reason.clone();
+            Timeout::new(timeout_ms, move || {
+                if reason.get().is_none() {
+                    reason.set(Some(AbortReason::Timeout));
+                    controller.abort();
+                }
+            })
+        };
+        Abort {
+            controller,
+            reason,
+            _timeout: Rc::new(timeout),
+        }
+    }
+
+    fn abort_with(&self, reason: AbortReason) {
+        if self.reason.get().is_none() {
+            self.reason.set(Some(reason));
+            self.controller.abort();
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.abort_with(AbortReason::Cancelled);
+    }
+
+    fn is_aborted(&self) -> bool {
+        self.reason.get().is_some()
+    }
+
+    // maps a rejected promise to why it was rejected
+    fn error(&self, error: JsValue) -> FetchError {
+        match self.reason.get() {
+            Some(AbortReason::Timeout) => FetchError::Timeout,
+            Some(AbortReason::Cancelled) => FetchError::Cancelled,
+            Some(AbortReason::Unmounted) => FetchError::Unmounted,
+            None => FetchError::Network(
+                error
+                    .dyn_ref::<js_sys::Error>()
+                    .map(|e| String::from(e.message()))
+                    .or_else(|| error.as_string())
+                    .unwrap_or_else(|| t("network-error")),
+            ),
+        }
+    }
+}
+
+// requests started through a scope are aborted when its component unmounts
+#[derive(Clone, Default)]
+pub struct AbortScope {
+    active: Rc<RefCell<Vec<Abort>>>,
+}
+
+impl AbortScope {
+    pub fn start(&self, timeout_ms: u32) -> Abort {
+        let abort = Abort::with_timeout(timeout_ms);
+        let mut active = self.active.borrow_mut();
+        active.retain(|a| !a.is_aborted());
+        active.push(abort.clone());
+        abort
+    }
+
+    fn abort_all(&self) {
+        for abort in self.active.borrow_mut().drain(..) {
+            abort.abort_with(AbortReason::Unmounted);
+        }
+    }
+}
+
+pub fn use_abort_scope() -> AbortScope {
+    let scope = (*use_state(AbortScope::default)).clone();
+    {
+        let scope = scope.clone();
+        use_effect_with_deps(move |_| move || scope.abort_all(), ());
+    }
+    scope
+}
+
+async fn send(url: &str, init: &RequestInit, abort: &Abort) -> Result<Response, FetchError> {
+    let window = web_sys::window().ok_or_else(|| FetchError::Network("no global `window` exists".to_string()))?;
+    init.set_signal(Some(&abort.controller.signal()));
+    let response = JsFuture::from(window.fetch_with_str_and_init(url, init))
+        .await
+        .map_err(|e| abort.error(e))?;
+    response.dyn_into::<Response>().map_err(|_| FetchError::InvalidResponse)
+}
+
+pub async fn get(url: &str, abort: &Abort) -> Result<Response, FetchError> {
+    send(url, &RequestInit::new(), abort).await
+}
+
+pub async fn post_json<T: Serialize>(url: &str, body: &T, abort: &Abort) -> Result<Response, FetchError> {
+    let body = serde_json::to_string(body).map_err(|_| FetchError::InvalidRequest)?;
+    let headers = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&headers, &"Content-Type".into(), &"application/json".into());
+
+    let init = RequestInit::new();
+    init.set_method("POST");
+    init.set_headers(&headers);
+    init.set_body(&JsValue::from_str(&body));
+    send(url, &init, abort).await
+}
+
+pub async fn text(response: &Response, abort: &Abort) -> Result<String, FetchError> {
+    let promise = response.text().map_err(|e| abort.error(e))?;
+    JsFuture::from(promise)
+        .await
+        .map_err(|e| abort.error(e))?
+        .as_string()
+        .ok_or(FetchError::InvalidResponse)
+}
+
+pub async fn json<T: DeserializeOwned>(response: &Response, abort: &Abort) -> Result<T, FetchError> {
+    let promise = response.json().map_err(|_| FetchError::InvalidResponse)?;
+    let value = JsFuture::from(promise).await.map_err(|e| match abort.error(e) {
+        FetchError::Network(_) => FetchError::InvalidResponse,
+        e => e,
+    })?;
+    serde_wasm_bindgen::from_value(value).map_err(|_| FetchError::InvalidResponse)
+}
+
+// reads a response body line by line as chunks arrive
+pub struct LineReader {
+    reader: ReadableStreamDefaultReader,
+    abort: Abort,
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl LineReader {
+    pub fn new(response: &Response, abort: &Abort) -> Option<Self> {
+        let reader = response.body()?.get_reader().unchecked_into::<ReadableStreamDefaultReader>();
+        Some(LineReader {
+            reader,
+            abort: abort.clone(),
+            buffer: Vec::new(),
+            done: false,
+        })
+    }
+
+    // next complete line, or the unterminated tail once the stream ends
+    pub async fn next_line(&mut self) -> Result<Option<String>, FetchError> {
+        loop {
+            if let Some(pos) = self.buffer.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+                return Ok(Some(String::from_utf8_lossy(&line).trim_end().to_string()));
+            }
+
+            if self.done {
+                if self.buffer.is_empty() {
+                    return Ok(None);
+                }
+                let line = std::mem::take(&mut self.buffer);
+                return Ok(Some(String::from_utf8_lossy(&line).trim_end().to_string()));
+            }
+
+            let chunk = JsFuture::from(self.reader.read())
+                .await
+                .map_err(|e| self.abort.error(e))?;
+            let done = js_sys::Reflect::get(&chunk, &JsValue::from_str("done")).map_err(|_| FetchError::InvalidResponse)?;
+            if done.as_bool().unwrap_or(false) {
+                self.done = true;
+            } else {
+                let value = js_sys::Reflect::get(&chunk, &JsValue::from_str("value")).map_err(|_| FetchError::InvalidResponse)?;
+                self.buffer
+                    .extend(value.unchecked_into::<js_sys::Uint8Array>().to_vec());
+            }
+        }
+    }
+}
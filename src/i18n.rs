@@ -0,0 +1,239 @@
+use std::cell::Cell;
+use yew::prelude::*;
+use wasm_bindgen::prelude::*;
+use web_sys::HtmlSelectElement;
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use num_format::ToFormattedString;
+use unic_langid::LanguageIdentifier;
+
+use crate::local_storage;
+
+const LOCALE_KEY: &str = "locale";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Locale {
+    En,
+    Es,
+    De,
+}
+
+// same order as BUNDLES
+const LOCALES: [Locale; 3] = [Locale::En, Locale::Es, Locale::De];
+
+impl Locale {
+    fn tag(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::De => "de",
+        }
+    }
+
+    // each language is listed under its own name
+    fn label(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+            Locale::De => "Deutsch",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Locale::En => include_str!("../locales/en.ftl"),
+            Locale::Es => include_str!("../locales/es.ftl"),
+            Locale::De => include_str!("../locales/de.ftl"),
+        }
+    }
+
+    fn number_locale(self) -> num_format::Locale {
+        match self {
+            Locale::En => num_format::Locale::en,
+            Locale::Es => num_format::Locale::es,
+            Locale::De => num_format::Locale::de,
+        }
+    }
+
+    // matches on the language only, so "de-AT" gets German
+    fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next()?.to_ascii_lowercase();
+        LOCALES.into_iter().find(|locale| locale.tag() == language)
+    }
+
+    // the saved choice, then the browser's preferred languages, then English
+    fn detect() -> Self {
+        if let Some(locale) = local_storage()
+            .and_then(|s| s.get_item(LOCALE_KEY).ok().flatten())
+            .and_then(|tag| Locale::from_tag(&tag))
+        {
+            return locale;
+        }
+        let Some(navigator) = web_sys::window().map(|w| w.navigator()) else {
+            return Locale::En;
+        };
+        navigator
+            .languages()
+            .iter()
+            .filter_map(|tag| tag.as_string())
+            .chain(navigator.language())
+            .find_map(|tag| Locale::from_tag(&tag))
+            .unwrap_or(Locale::En)
+    }
+}
+
+fn bundle(locale: Locale) -> FluentBundle<FluentResource> {
+    let resource = FluentResource::try_new(locale.source().to_string()).expect("invalid message bundle");
+    let langid: LanguageIdentifier = locale.tag().parse().expect("invalid language tag");
+    let mut bundle = FluentBundle::new(vec![langid]);
+    // messages carry emails and tokens people copy, keep bidi marks out of them
+    bundle.set_use_isolating(false);
+    bundle.add_resource(resource).expect("duplicate message id");
+    bundle
+}
+
+thread_local! {
+    static BUNDLES: Vec<FluentBundle<FluentResource>> = LOCALES.into_iter().map(bundle).collect();
+    // for code outside components, kept in step by I18nProvider
+    static CURRENT: Cell<Locale> = const { Cell::new(Locale::En) };
+}
+
+fn format(locale: Locale, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+    BUNDLES.with(|bundles| {
+        let bundle = &bundles[locale as usize];
+        let pattern = bundle.get_message(id)?.value()?;
+        let mut errors = Vec::new();
+        Some(bundle.format_pattern(pattern, args, &mut errors).into_owned())
+    })
+}
+
+// missing translations fall back to English, then to the id itself
+fn translate(locale: Locale, id: &str, args: Option<&FluentArgs>) -> String {
+    format(locale, id, args)
+        .or_else(|| format(Locale::En, id, args))
+        .unwrap_or_else(|| id.to_string())
+}
+
+pub fn t(id: &str) -> String {
+    translate(CURRENT.with(Cell::get), id, None)
+}
+
+pub fn t_args<'a>(id: &str, args: impl IntoIterator<Item = (&'a str, FluentValue<'a>)>) -> String {
+    let args: FluentArgs = args.into_iter().collect();
+    translate(CURRENT.with(Cell::get), id, Some(&args))
+}
+
+pub fn format_number(n: u32) -> String {
+    n.to_formatted_string(&CURRENT.with(Cell::get).number_locale())
+}
+
+// date and time of a js timestamp, in the browser's formatting for the locale
+pub fn format_date_time(ms: f64) -> String {
+    let options = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&options, &"dateStyle".into(), &"medium".into());
+    let _ = js_sys::Reflect::set(&options, &"timeStyle".into(), &"short".into());
+    js_sys::Date::new(&JsValue::from_f64(ms))
+        .to_locale_string(CURRENT.with(Cell::get).tag(), &options)
+        .into()
+}
+
+#[derive(Clone, PartialEq)]
+pub struct I18n {
+    pub locale: Locale,
+    set_locale: Callback<Locale>,
+}
+
+impl I18n {
+    pub fn t(&self, id: &str) -> String {
+        translate(self.locale, id, None)
+    }
+
+    pub fn t_args<'a>(&self, id: &str, args: impl IntoIterator<Item = (&'a str, FluentValue<'a>)>) -> String {
+        let args: FluentArgs = args.into_iter().collect();
+        translate(self.locale, id, Some(&args))
+    }
+
+    pub fn set_locale(&self, locale: Locale) {
+        self.set_locale.emit(locale);
+    }
+}
+
+// components that show text call this so they re-render when the language changes
+pub fn use_i18n() -> I18n {
+    use_context::<I18n>().expect("use_i18n called outside of I18nProvider")
+}
+
+#[derive(Properties, PartialEq)]
+pub struct I18nProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+#[function_component(I18nProvider)]
+pub fn i18n_provider(props: &I18nProviderProps) -> Html {
+    let locale = use_state_eq(Locale::detect);
+    // set before the children render so they and their callbacks see it
+    CURRENT.with(|current| current.set(*locale));
+
+    use_effect_with_deps(
+        |locale: &Locale| {
+            if let Some(root) = web_sys::window()
+                .and_then(|w| w.document())
+                .and_then(|d| d.document_element())
+            {
+                let _ = root.set_attribute("lang", locale.tag());
+            }
+            || ()
+        },
+        *locale,
+    );
+
+    let set_locale = {
+        let locale = locale.clone();
+        Callback::from(move |next: Locale| {
+            if let Some(storage) = local_storage() {
+                let _ = storage.set_item(LOCALE_KEY, next.tag());
+            }
+            locale.set(next);
+        })
+    };
+
+    let i18n = I18n {
+        locale: *locale,
+        set_locale,
+    };
+
+    html! {
+        <ContextProvider<I18n> context={i18n}>
+            { for props.children.iter() }
+        </ContextProvider<I18n>>
+    }
+}
+
+#[function_component(LanguagePicker)]
+pub fn language_picker() -> Html {
+    let i18n = use_i18n();
+
+    let onchange = {
+        let i18n = i18n.clone();
+        Callback::from(move |e: Event| {
+            if let Some(locale) = e
+                .target_dyn_into::<HtmlSelectElement>()
+                .and_then(|target| Locale::from_tag(&target.value()))
+            {
+                i18n.set_locale(locale);
+            }
+        })
+    };
+
+    html! {
+        <select class="form-select form-select-sm w-auto" aria-label={i18n.t("language-label")} onchange={onchange}>
+            {LOCALES.iter().map(|locale| {
+                html! {
+                    <option value={locale.tag()} lang={locale.tag()} selected={i18n.locale == *locale}>
+                        {locale.label()}
+                    </option>
+                }
+            }).collect::<Html>()}
+        </select>
+    }
+}
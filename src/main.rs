@@ -1,30 +1,65 @@
+// yew 0.19's html! macro expands component props into statements clippy flags.
+// this has to be crate-wide: function_component moves the body into a generated
+// impl without the fn's attributes, and attributes on expressions aren't stable
+#![allow(clippy::unnecessary_operation, clippy::let_unit_value)]
+
 use yew::prelude::*;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::HtmlSelectElement;
 use wasm_bindgen::JsCast;
-use js_sys;
 use wasm_bindgen::closure::Closure;
-use reqwest;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
-use std::cell::RefCell;
-use gloo_timers::callback::Timeout;
+use std::cell::{Cell, RefCell};
+use gloo_timers::callback::{Interval, Timeout};
+
+mod api;
+mod bulk_table;
+mod clipboard;
+mod cooldown;
+mod credentials;
+mod fetch;
+mod i18n;
+mod report;
+mod sync;
+mod theme;
+mod toast;
+
+use api::ApiError;
+use bulk_table::BulkTable;
+use clipboard::ClipboardSettings;
+use cooldown::{format_remaining, use_cooldown};
+use credentials::CredentialCard;
+use fetch::{use_abort_scope, Abort, FetchError, LineReader, API_TIMEOUT_MS};
+use i18n::{format_number, t, t_args, use_i18n, I18nProvider, LanguagePicker};
+use report::ReportButton;
+use sync::{use_leader, use_sync, SyncMessage};
+use theme::{use_theme, ThemePicker, ThemeProvider};
+use toast::{use_toaster, ToastProvider};
 
-#[derive(Deserialize, Clone, Default)]
+#[allow(dead_code)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 struct EmailCountResponse {
     code: u32,
     data: u32,
     success: bool,
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 struct AvailableEmails {
     allocated: EmailCountResponse,
     hotmail: EmailCountResponse,
     outlook: EmailCountResponse,
 }
 
+// accounts per bulk request, used when the server doesn't declare a batch size
+const BULK_SIZE: u32 = 10;
+// bulk batches take a while server side, give them longer than single requests
+const BULK_TIMEOUT_MS: u32 = 120_000;
+// how often the leader tab refreshes stock for every open tab
+const STOCK_POLL_MS: u32 = 60_000;
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = grecaptcha)]
@@ -32,7 +67,7 @@ extern "C" {
 }
 
 // struct to handle email data
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 struct EmailData {
     email: String,
     password: String,
@@ -40,22 +75,117 @@ struct EmailData {
     client_id: String,
 }
 
-// struct to handle bulk email data
-#[derive(Clone, Default)]
-struct BulkEmailData {
-    email: String,
-    password: String,
-    refresh_token: String,
-    client_id: String,
+impl EmailData {
+    // parse an email:password:refreshToken:clientID line
+    fn from_line(line: &str) -> Option<Self> {
+        let parts: Vec<&str> = line.split(':').collect();
+        if parts.len() == 4 {
+            Some(EmailData {
+                email: parts[0].to_string(),
+                password: parts[1].to_string(),
+                refresh_token: parts[2].to_string(),
+                client_id: parts[3].to_string(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+enum BulkAction {
+    Clear,
+    // a streamed line, appended so rows replaced meanwhile are kept
+    Push(EmailData),
+    // swap a reported account for its replacement, wherever its row is now
+    Replace { email: String, replacement: EmailData },
+}
+
+// bulk results, updated in place so concurrent reports don't undo each other
+#[derive(Default, PartialEq)]
+struct BulkEmails {
+    emails: Vec<EmailData>,
+}
+
+impl Reducible for BulkEmails {
+    type Action = BulkAction;
+
+    fn reduce(self: Rc<Self>, action: BulkAction) -> Rc<Self> {
+        let mut emails = self.emails.clone();
+        match action {
+            BulkAction::Clear => emails.clear(),
+            // the table keys rows by email, so each account is listed once
+            BulkAction::Push(data) => {
+                if !emails.iter().any(|row| row.email == data.email) {
+                    emails.push(data);
+                }
+            }
+            BulkAction::Replace { email, replacement } => {
+                let listed = replacement.email != email && emails.iter().any(|row| row.email == replacement.email);
+                if listed {
+                    emails.retain(|row| row.email != email);
+                } else if let Some(row) = emails.iter_mut().find(|row| row.email == email) {
+                    *row = replacement;
+                }
+            }
+        }
+        Rc::new(BulkEmails { emails })
+    }
+}
+
+// how a finished bulk request is reported
+enum BulkOutcome {
+    // the generator went away, nothing left to update
+    Unmounted,
+    Cancelled,
+    // nothing usable arrived
+    Failed(FetchError),
+    Empty,
+    // the stream stopped early, the rows received so far are kept
+    TimedOut,
+    ConnectionLost,
+    // the server reported an error after some accounts had arrived
+    Partial(FetchError),
+    Done,
+}
+
+impl BulkOutcome {
+    // seconds to hold the bulk button back for
+    fn cooldown(&self) -> Option<u64> {
+        match self {
+            BulkOutcome::Failed(FetchError::RateLimited(secs))
+            | BulkOutcome::Partial(FetchError::RateLimited(secs)) => Some(*secs),
+            _ => None,
+        }
+    }
+}
+
+fn bulk_outcome(result: Result<(), FetchError>, received: usize, server_error: Option<FetchError>) -> BulkOutcome {
+    match result {
+        Err(FetchError::Unmounted) => BulkOutcome::Unmounted,
+        Err(FetchError::Cancelled) => BulkOutcome::Cancelled,
+        Err(e @ FetchError::RateLimited(_)) => BulkOutcome::Failed(e),
+        Err(e) if received == 0 => BulkOutcome::Failed(e),
+        // BULK_TIMEOUT_MS covers the whole stream, so a slow batch can run out mid-way
+        Err(FetchError::Timeout) => BulkOutcome::TimedOut,
+        Err(_) => BulkOutcome::ConnectionLost,
+        Ok(()) => match server_error {
+            Some(e) if received == 0 => BulkOutcome::Failed(e),
+            Some(e) => BulkOutcome::Partial(e),
+            None if received == 0 => BulkOutcome::Empty,
+            None => BulkOutcome::Done,
+        },
+    }
 }
 
 // failed demo counter - might be removed soon
+#[allow(dead_code)]
 #[derive(Clone)]
 struct AnimatedCounter {
     current: Rc<RefCell<f64>>,
     target: u32,
 }
 
+#[allow(dead_code)]
 impl AnimatedCounter {
     fn new(initial: u32) -> Self {
         AnimatedCounter {
@@ -100,6 +230,7 @@ impl AnimatedCounter {
     }
 }
 
+#[allow(dead_code)]
 fn request_animation_frame(f: &Closure<dyn FnMut()>) {
     web_sys::window()
         .unwrap()
@@ -107,35 +238,141 @@ fn request_animation_frame(f: &Closure<dyn FnMut()>) {
         .unwrap();
 }
 
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+}
+
+thread_local! {
+    // id of the current recaptcha widget, it changes each time the theme does
+    static RECAPTCHA_WIDGET: Cell<Option<f64>> = const { Cell::new(None) };
+}
+
+fn recaptcha_loaded() -> bool {
+    let window = web_sys::window().expect("no global `window` exists");
+    js_sys::Reflect::get(&window, &JsValue::from_str("grecaptcha"))
+        .and_then(|grecaptcha| js_sys::Reflect::get(&grecaptcha, &JsValue::from_str("render")))
+        .map(|render_fn| render_fn.is_function())
+        .unwrap_or(false)
+}
+
+fn render_recaptcha(theme_name: &str, recaptcha_token: UseStateHandle<String>) {
+    let window = web_sys::window().expect("no global `window` exists");
+    let document = window.document().expect("should have a document on window");
+    let grecaptcha = js_sys::Reflect::get(&window, &JsValue::from_str("grecaptcha"))
+        .expect("Failed to get grecaptcha object");
+    let Some(container) = document.get_element_by_id("recaptcha-container") else {
+        return;
+    };
+
+    // a widget can't be rendered twice into one element, so swap in a fresh one
+    container.set_inner_html("");
+    let widget = document.create_element("div").unwrap();
+    container.append_child(&widget).unwrap();
+
+    let render_params = js_sys::Object::new();
+    js_sys::Reflect::set(&render_params, &JsValue::from_str("sitekey"), &JsValue::from_str("6LeJlSkqAAAAAMTxxKkrTk32jencF8Go7KqC-6v8")).unwrap();
+    js_sys::Reflect::set(&render_params, &JsValue::from_str("theme"), &JsValue::from_str(theme_name)).unwrap();
+
+    let token_setter = recaptcha_token.clone();
+    js_sys::Reflect::set(&render_params, &JsValue::from_str("callback"), &Closure::wrap(Box::new(move |token: String| {
+        token_setter.set(token);
+    }) as Box<dyn FnMut(String)>).into_js_value()).unwrap();
+
+    let widget_id = js_sys::Reflect::get(&grecaptcha, &JsValue::from_str("render"))
+        .and_then(|render_fn| {
+            let render_fn = render_fn.dyn_into::<js_sys::Function>().unwrap();
+            render_fn.call2(&JsValue::NULL, &widget, &render_params)
+        })
+        .expect("Failed to render reCAPTCHA");
+    RECAPTCHA_WIDGET.with(|id| id.set(widget_id.as_f64()));
+    // any token belonged to the old widget
+    recaptcha_token.set(String::new());
+
+    web_sys::console::log_1(&"reCAPTCHA rendered successfully".into());
+}
+
+fn reset_recaptcha() {
+    let window = web_sys::window().expect("no global `window` exists");
+    let grecaptcha = js_sys::Reflect::get(&window, &JsValue::from_str("grecaptcha"))
+        .expect("Failed to get grecaptcha object");
+    let widget_id = RECAPTCHA_WIDGET.with(|id| id.get());
+    js_sys::Reflect::get(&grecaptcha, &JsValue::from_str("reset"))
+        .and_then(|reset_fn| {
+            let reset_fn = reset_fn.dyn_into::<js_sys::Function>().unwrap();
+            match widget_id {
+                Some(widget_id) => reset_fn.call1(&JsValue::NULL, &JsValue::from_f64(widget_id)),
+                None => reset_fn.call0(&JsValue::NULL),
+            }
+        })
+        .expect("Failed to reset reCAPTCHA");
+}
+
 #[function_component(App)]
 fn app() -> Html {
-    let _email = use_state(|| String::new());
+    html! {
+        <ThemeProvider>
+            <I18nProvider>
+                <ToastProvider>
+                    <Generator />
+                </ToastProvider>
+            </I18nProvider>
+        </ThemeProvider>
+    }
+}
+
+#[function_component(Generator)]
+fn generator() -> Html {
+    let toaster = use_toaster();
+    let theme = use_theme();
+    let i18n = use_i18n();
+    let scope = use_abort_scope();
+    // in-flight generation requests, for the cancel buttons
+    let single_abort = use_mut_ref(|| None::<Abort>);
+    let bulk_abort = use_mut_ref(|| None::<Abort>);
+    let single_cooldown = use_cooldown("single");
+    let bulk_cooldown = use_cooldown("bulk");
+    let is_leader = use_leader();
+    let _email = use_state(String::new);
     let loading = use_state(|| false);
     let email_type = use_state(|| "random".to_string());
-    let available_emails = use_state(|| AvailableEmails::default());
-    let recaptcha_token = use_state(|| String::new());
-    let bulk_emails = use_state(|| Vec::<BulkEmailData>::new());
+    let available_emails = use_state(AvailableEmails::default);
+    let recaptcha_token = use_state(String::new);
+    let bulk_emails = use_reducer(BulkEmails::default);
     let bulk_loading = use_state(|| false);
-    let email_data = use_state(|| EmailData::default());
-    let show_credentials = use_state(|| true);
-    let show_bulk_credentials = use_state(|| true);
-    let error_message = use_state(|| String::new());
+    // accounts received so far against the batch size
+    let bulk_progress = use_state(|| (0u32, BULK_SIZE));
+    let email_data = use_state(EmailData::default);
+    // read out by the status region while a request runs
+    let status = use_state(String::new);
+    let single_heading = use_node_ref();
+    let bulk_heading = use_node_ref();
+    // results heading to move focus to once it has rendered
+    let pending_focus = use_state(|| None::<NodeRef>);
 
     // Function to fetch and update stock data
     let update_stock_data = {
         let available_emails = available_emails.clone();
+        let toaster = toaster.clone();
+        let scope = scope.clone();
         
         Rc::new(RefCell::new(move || {
             let available_emails = available_emails.clone();
+            let toaster = toaster.clone();
+            let abort = scope.start(API_TIMEOUT_MS);
             
             spawn_local(async move {
-                let response = reqwest::get("https://burneroutlook-api.vercel.app/check_balance")
-                    .await
-                    .unwrap()
-                    .json::<AvailableEmails>()
-                    .await
-                    .unwrap();
-                available_emails.set(response);
+                let response = match fetch::get("https://burneroutlook-api.vercel.app/check_balance", &abort).await {
+                    Ok(response) => fetch::json::<AvailableEmails>(&response, &abort).await,
+                    Err(e) => Err(e),
+                };
+                match response {
+                    Ok(response) => {
+                        sync::broadcast(&SyncMessage::Stock { stock: response.clone() });
+                        available_emails.set(response);
+                    }
+                    Err(FetchError::Unmounted) => {}
+                    Err(_) => toaster.warning(t("stock-load-failed")),
+                }
             });
         }))
     };
@@ -161,72 +398,99 @@ fn app() -> Html {
         );
     }
 
+    // only the leader tab keeps polling, the others pick up its broadcasts
+    {
+        let update_stock_data = update_stock_data.clone();
+        use_effect_with_deps(
+            move |is_leader| {
+                let poll = is_leader.then(|| {
+                    Interval::new(STOCK_POLL_MS, move || {
+                        (update_stock_data.borrow_mut())();
+                    })
+                });
+                move || drop(poll)
+            },
+            is_leader,
+        );
+    }
+    {
+        let available_emails = available_emails.clone();
+        use_sync(move |message| {
+            if let SyncMessage::Stock { stock } = message {
+                available_emails.set(stock);
+            }
+        });
+    }
+
     let onclick = {
         let email_data = email_data.clone();
         let loading = loading.clone();
         let email_type = email_type.clone();
         let recaptcha_token = recaptcha_token.clone();
+        let toaster = toaster.clone();
+        let scope = scope.clone();
+        let single_abort = single_abort.clone();
+        let single_cooldown = single_cooldown.clone();
+        let status = status.clone();
+        let pending_focus = pending_focus.clone();
+        let single_heading = single_heading.clone();
         Callback::from(move |_| {
             let email_data = email_data.clone();
+            let single_cooldown = single_cooldown.clone();
+            let status = status.clone();
+            let pending_focus = pending_focus.clone();
+            let single_heading = single_heading.clone();
             let loading = loading.clone();
             let email_type = email_type.clone();
             let recaptcha_token = recaptcha_token.clone();
+            let toaster = toaster.clone();
+            if recaptcha_token.is_empty() {
+                // nudge the user if recaptcha isn't clicked yet
+                toaster.warning(t("captcha-required"));
+                return;
+            }
+
             loading.set(true);
+            status.set(t("generating"));
+            let abort = scope.start(API_TIMEOUT_MS);
+            *single_abort.borrow_mut() = Some(abort.clone());
             spawn_local(async move {
-                if recaptcha_token.is_empty() {
-                    // show an error message if recaptcha isn't clicked yet
-                    web_sys::window()
-                        .unwrap()
-                        .alert_with_message("Please complete the reCAPTCHA")
-                        .unwrap();
-                    loading.set(false);
-                    return;
-                }
-
-                let response = reqwest::get(&format!(
+                let response = match fetch::get(&format!(
                     "https://burneroutlook-api.vercel.app/get_email?email_type={}&captcha={}",
                     *email_type,
                     *recaptcha_token
-                ))
-                .await
-                .unwrap()
-                .text()
+                ), &abort)
                 .await
-                .unwrap();
-
-                if response.starts_with("Error:") {
-                    email_data.set(EmailData {
-                        email: response,
-                        ..Default::default()
-                    });
-                } else {
-                    let parts: Vec<&str> = response.split(':').collect();
-                    if parts.len() == 4 {
-                        email_data.set(EmailData {
-                            email: parts[0].to_string(),
-                            password: parts[1].to_string(),
-                            refresh_token: parts[2].to_string(),
-                            client_id: parts[3].to_string(),
-                        });
-                    } else {
-                        email_data.set(EmailData {
-                            email: "Error: Invalid response format".to_string(),
-                            ..Default::default()
-                        });
+                {
+                    Ok(response) => api::read_text(&response, &abort).await,
+                    Err(e) => Err(e),
+                };
+
+                match response {
+                    // the page is gone, leave its state alone
+                    Err(FetchError::Unmounted) => return,
+                    Err(FetchError::Cancelled) => toaster.info(t("generation-cancelled")),
+                    Err(e @ FetchError::RateLimited(secs)) => {
+                        single_cooldown.start(secs);
+                        toaster.error(e.message());
+                    }
+                    Err(e) => toaster.error(e.message()),
+                    Ok(response) => {
+                        if let Some(data) = EmailData::from_line(&response) {
+                            toaster.success(t_args("generated-single", [("email", data.email.as_str().into())]));
+                            email_data.set(data);
+                            pending_focus.set(Some(single_heading));
+                        } else {
+                            toaster.error(FetchError::InvalidResponse.message());
+                        }
                     }
                 }
                 loading.set(false);
-                
-                // reset recaptcha
-                let window = web_sys::window().expect("no global `window` exists");
-                let grecaptcha = js_sys::Reflect::get(&window, &JsValue::from_str("grecaptcha"))
-                    .expect("Failed to get grecaptcha object");
-                js_sys::Reflect::get(&grecaptcha, &JsValue::from_str("reset"))
-                    .and_then(|reset_fn| {
-                        let reset_fn = reset_fn.dyn_into::<js_sys::Function>().unwrap();
-                        reset_fn.call0(&JsValue::NULL)
-                    })
-                    .expect("Failed to reset reCAPTCHA");
+                // the outcome is announced by its toast
+                status.set(String::new());
+
+                // the captcha token is spent once it's been sent
+                reset_recaptcha();
                 recaptcha_token.set(String::new());
             });
         })
@@ -235,79 +499,141 @@ fn app() -> Html {
     let bulk_onclick = {
         let bulk_emails = bulk_emails.clone();
         let bulk_loading = bulk_loading.clone();
+        let bulk_progress = bulk_progress.clone();
         let email_type = email_type.clone();
         let recaptcha_token = recaptcha_token.clone();
-        let error_message = error_message.clone();
+        let toaster = toaster.clone();
+        let scope = scope.clone();
+        let bulk_abort = bulk_abort.clone();
+        let bulk_cooldown = bulk_cooldown.clone();
+        let status = status.clone();
+        let pending_focus = pending_focus.clone();
+        let bulk_heading = bulk_heading.clone();
         Callback::from(move |_| {
             let bulk_emails = bulk_emails.clone();
+            let bulk_cooldown = bulk_cooldown.clone();
+            let status = status.clone();
+            let pending_focus = pending_focus.clone();
+            let bulk_heading = bulk_heading.clone();
             let bulk_loading = bulk_loading.clone();
+            let bulk_progress = bulk_progress.clone();
             let email_type = email_type.clone();
             let recaptcha_token = recaptcha_token.clone();
-            let error_message = error_message.clone();
+            let toaster = toaster.clone();
+            if recaptcha_token.is_empty() {
+                toaster.warning(t("captcha-required"));
+                return;
+            }
+
             bulk_loading.set(true);
-            error_message.set(String::new()); // clear any previous error message
+            bulk_progress.set((0, BULK_SIZE));
+            status.set(t("generating"));
+            let abort = scope.start(BULK_TIMEOUT_MS);
+            *bulk_abort.borrow_mut() = Some(abort.clone());
             spawn_local(async move {
-                if recaptcha_token.is_empty() {
-                    web_sys::window()
-                        .unwrap()
-                        .alert_with_message("Please complete the reCAPTCHA")
-                        .unwrap();
-                    bulk_loading.set(false);
-                    return;
-                }
-
-                let response = reqwest::get(&format!(
+                let response = fetch::get(&format!(
                     "https://burneroutlook-api.vercel.app/get_bulk_emails?email_type={}&captcha={}",
                     *email_type,
                     *recaptcha_token
-                ))
-                .await
-                .unwrap();
+                ), &abort)
+                .await;
 
-                if response.status() == 429 {
-                    error_message.set("Rate limit exceeded. Try again in 24 hours.".to_string());
-                    bulk_loading.set(false);
-                    return;
-                }
+                // accounts received, the rows themselves go straight into bulk_emails
+                let mut received = 0usize;
+                let mut total = BULK_SIZE;
+                let mut server_error = None;
+                let result = match response {
+                    // 429s and error envelopes, read whole rather than line by line
+                    Ok(response) if !response.ok() => Err(api::read_text(&response, &abort)
+                        .await
+                        .err()
+                        .unwrap_or(FetchError::InvalidResponse)),
+                    Ok(response) => {
+                        total = response
+                            .headers()
+                            .get("X-Batch-Size")
+                            .ok()
+                            .flatten()
+                            .and_then(|size| size.parse().ok())
+                            .unwrap_or(BULK_SIZE);
+                        bulk_progress.set((0, total));
 
-                let response_text = response.text().await.unwrap();
-
-                // parse the response into bulkemaildata structs
-                let emails: Vec<BulkEmailData> = response_text
-                    .lines()
-                    .filter_map(|line| {
-                        let parts: Vec<&str> = line.split(':').collect();
-                        if parts.len() == 4 {
-                            Some(BulkEmailData {
-                                email: parts[0].to_string(),
-                                password: parts[1].to_string(),
-                                refresh_token: parts[2].to_string(),
-                                client_id: parts[3].to_string(),
-                            })
-                        } else {
-                            None
+                        // render each account as its line arrives, keeping what we have if the stream breaks
+                        bulk_emails.dispatch(BulkAction::Clear);
+                        match LineReader::new(&response, &abort) {
+                            Some(mut reader) => loop {
+                                match reader.next_line().await {
+                                    Ok(Some(line)) => {
+                                        if let Some(data) = EmailData::from_line(&line) {
+                                            received += 1;
+                                            bulk_emails.dispatch(BulkAction::Push(data));
+                                            bulk_progress.set((received as u32, total));
+                                            status.set(t_args(
+                                                "bulk-progress-status",
+                                                [("received", received.into()), ("total", total.into())],
+                                            ));
+                                        } else if let Some(error) = ApiError::parse(&line) {
+                                            server_error = Some(error.into_fetch_error());
+                                        }
+                                    }
+                                    Ok(None) => break Ok(()),
+                                    Err(e) => break Err(e),
+                                }
+                            },
+                            None => Ok(()),
                         }
-                    })
-                    .collect();
+                    }
+                    Err(e) => Err(e),
+                };
 
-                bulk_emails.set(emails);
+                let outcome = bulk_outcome(result, received, server_error);
+                if let Some(secs) = outcome.cooldown() {
+                    bulk_cooldown.start(secs);
+                }
+                let counts = [("received", received.into()), ("total", total.into())];
+                match outcome {
+                    BulkOutcome::Unmounted => return,
+                    BulkOutcome::Cancelled => toaster.info(t_args("bulk-cancelled", counts)),
+                    BulkOutcome::Failed(e) => toaster.error(e.message()),
+                    BulkOutcome::Empty => toaster.error(t("bulk-empty")),
+                    BulkOutcome::TimedOut => toaster.warning(t_args("bulk-timed-out", counts)),
+                    BulkOutcome::ConnectionLost => toaster.warning(t_args("bulk-connection-lost", counts)),
+                    BulkOutcome::Partial(e) => toaster.warning(t_args(
+                        "bulk-partial",
+                        counts.into_iter().chain([("error", e.message().into())]),
+                    )),
+                    BulkOutcome::Done => toaster.success(t_args("generated-bulk", [("count", received.into())])),
+                }
                 bulk_loading.set(false);
-                
-                // reset recaptcha (same as before)
-                let window = web_sys::window().expect("no global `window` exists");
-                let grecaptcha = js_sys::Reflect::get(&window, &JsValue::from_str("grecaptcha"))
-                    .expect("Failed to get grecaptcha object");
-                js_sys::Reflect::get(&grecaptcha, &JsValue::from_str("reset"))
-                    .and_then(|reset_fn| {
-                        let reset_fn = reset_fn.dyn_into::<js_sys::Function>().unwrap();
-                        reset_fn.call0(&JsValue::NULL)
-                    })
-                    .expect("Failed to reset reCAPTCHA");
+                status.set(String::new());
+                if received > 0 {
+                    pending_focus.set(Some(bulk_heading));
+                }
+
+                reset_recaptcha();
                 recaptcha_token.set(String::new());
             });
         })
     };
 
+    let cancel_single = {
+        let single_abort = single_abort.clone();
+        Callback::from(move |_| {
+            if let Some(abort) = single_abort.borrow().as_ref() {
+                abort.cancel();
+            }
+        })
+    };
+
+    let cancel_bulk = {
+        let bulk_abort = bulk_abort.clone();
+        Callback::from(move |_| {
+            if let Some(abort) = bulk_abort.borrow().as_ref() {
+                abort.cancel();
+            }
+        })
+    };
+
     let onchange = {
         let email_type = email_type.clone();
         Callback::from(move |e: Event| {
@@ -318,60 +644,48 @@ fn app() -> Html {
         })
     };
 
-    use_effect(move || {
-        let document = web_sys::window()
-            .expect("no global `window` exists")
-            .document()
-            .expect("should have a document on window");
-
-        // create a callback function
-        let recaptcha_token = recaptcha_token.clone();
-        let callback = Closure::wrap(Box::new(move || {
+    // render the recaptcha widget, and again whenever the theme flips since it can't restyle itself
+    use_effect_with_deps(
+        move |theme_name| {
+            let theme_name = *theme_name;
             let window = web_sys::window().expect("no global `window` exists");
-            let grecaptcha = js_sys::Reflect::get(&window, &JsValue::from_str("grecaptcha"))
-                .expect("Failed to get grecaptcha object");
+            if recaptcha_loaded() {
+                render_recaptcha(theme_name, recaptcha_token);
+            } else {
+                // set the callback as a global function, the script calls it once loaded
+                let callback = Closure::once_into_js(move || render_recaptcha(theme_name, recaptcha_token));
+                js_sys::Reflect::set(&window, &JsValue::from_str("onRecaptchaLoad"), &callback)
+                    .expect("Failed to set onRecaptchaLoad");
 
-            let render_params = js_sys::Object::new();
-            js_sys::Reflect::set(&render_params, &JsValue::from_str("sitekey"), &JsValue::from_str("6LeJlSkqAAAAAMTxxKkrTk32jencF8Go7KqC-6v8")).unwrap();
-            js_sys::Reflect::set(&render_params, &JsValue::from_str("theme"), &JsValue::from_str("light")).unwrap();
-            
-            let token_setter = recaptcha_token.clone();
-            js_sys::Reflect::set(&render_params, &JsValue::from_str("callback"), &Closure::wrap(Box::new(move |token: String| {
-                token_setter.set(token);
-            }) as Box<dyn FnMut(String)>).into_js_value()).unwrap();
-
-            js_sys::Reflect::get(&grecaptcha, &JsValue::from_str("render"))
-                .and_then(|render_fn| {
-                    let render_fn = render_fn.dyn_into::<js_sys::Function>().unwrap();
-                    render_fn.call2(
-                        &JsValue::NULL,
-                        &JsValue::from_str("recaptcha-container"),
-                        &render_params,
-                    )
-                })
-                .expect("Failed to render reCAPTCHA");
-
-            web_sys::console::log_1(&"reCAPTCHA rendered successfully".into());
-        }) as Box<dyn FnMut()>);
-
-        // add the recaptcha script
-        let script = document.create_element("script").unwrap();
-        script.set_attribute("src", "https://www.google.com/recaptcha/api.js?onload=onRecaptchaLoad&render=explicit").unwrap();
-        document.head().unwrap().append_child(&script).unwrap();
-
-        // set the callback as a global function
-        let window = web_sys::window().expect("no global `window` exists");
-        js_sys::Reflect::set(
-            &window,
-            &JsValue::from_str("onRecaptchaLoad"),
-            &callback.as_ref().unchecked_ref(),
-        ).expect("Failed to set onRecaptchaLoad");
-
-        // prevent the callback from being dropped
-        callback.forget();
-
-        || ()
-    });
+                // add the recaptcha script, once
+                let document = window.document().expect("should have a document on window");
+                if document.get_element_by_id("recaptcha-script").is_none() {
+                    let script = document.create_element("script").unwrap();
+                    script.set_attribute("id", "recaptcha-script").unwrap();
+                    script.set_attribute("src", "https://www.google.com/recaptcha/api.js?onload=onRecaptchaLoad&render=explicit").unwrap();
+                    document.head().unwrap().append_child(&script).unwrap();
+                }
+            }
+            || ()
+        },
+        theme.name(),
+    );
+
+    // move focus to new results so keyboard and screen reader users land on them
+    {
+        let target = (*pending_focus).clone();
+        let pending_focus = pending_focus.clone();
+        use_effect_with_deps(
+            move |target| {
+                if let Some(heading) = target.as_ref().and_then(|r| r.cast::<web_sys::HtmlElement>()) {
+                    let _ = heading.focus();
+                    pending_focus.set(None);
+                }
+                || ()
+            },
+            target,
+        );
+    }
 
     let info_expanded = use_state(|| false);
     let toggle_info = {
@@ -381,21 +695,26 @@ fn app() -> Html {
         })
     };
 
-    // callback for toggling between credentials and token/id
-    let toggle_view = {
-        let show_credentials = show_credentials.clone();
-        Callback::from(move |_| {
-            show_credentials.set(!*show_credentials);
+    // swap in the replacement account handed out for a reported one
+    let on_single_replacement = {
+        let email_data = email_data.clone();
+        Callback::from(move |data: EmailData| {
+            email_data.set(data);
         })
     };
-
-    // callback for toggling between credentials and token/id for bulk emails
-    let toggle_bulk_view = {
-        let show_bulk_credentials = show_bulk_credentials.clone();
-        Callback::from(move |_| {
-            show_bulk_credentials.set(!*show_bulk_credentials);
+    let on_bulk_replacement = {
+        let bulk_emails = bulk_emails.clone();
+        Callback::from(move |(email, replacement): (String, EmailData)| {
+            bulk_emails.dispatch(BulkAction::Replace { email, replacement });
         })
     };
+    let single_email = email_data.email.clone();
+    let has_results = !email_data.email.is_empty() || !bulk_emails.emails.is_empty();
+    // the spinner button's accessible name while it has no readable text
+    let bulk_progress_status = i18n.t_args(
+        "bulk-progress-status",
+        [("received", bulk_progress.0.into()), ("total", bulk_progress.1.into())],
+    );
 
     html! {
         <div class="min-vh-100 d-flex flex-column bg-gradient">
@@ -406,18 +725,18 @@ fn app() -> Html {
                             <div class="card shadow-lg border-0 rounded-lg">
                                 <div class="card-body p-5">
                                     <div class="text-center">
-                                    <img src="https://www.svgrepo.com/show/376347/rust.svg" alt="Rust Logo" style="width: 120px; height: 120px; margin-top: -40px;" />
+                                    <img src="/rust.svg" alt="" class="app-logo" />
                                     </div>
-                                    <h2 class="card-title text-center fw-bold position-relative" style="margin-top: -20px; z-index: 1;">{"Burner Outlook Generator"}</h2>
+                                    <h2 class="card-title text-center fw-bold position-relative app-title">{i18n.t("app-title")}</h2>
                                     <div class="text-center mb-4">
-                                        <h5 class="mb-3">{"Stock"}</h5>
+                                        <h5 class="mb-3">{i18n.t("stock-heading")}</h5>
                                         <div class="row">
                                             <div class="col-md-4">
                                                 <div class="card">
                                                     <div class="card-body">
-                                                        <h6 class="card-title">{"Outlook"}</h6>
+                                                        <h6 class="card-title">{i18n.t("email-type-outlook")}</h6>
                                                         <p class="card-text">
-                                                            {available_emails.outlook.data}
+                                                            {format_number(available_emails.outlook.data)}
                                                         </p>
                                                     </div>
                                                 </div>
@@ -425,9 +744,9 @@ fn app() -> Html {
                                             <div class="col-md-4">
                                                 <div class="card">
                                                     <div class="card-body">
-                                                        <h6 class="card-title">{"Hotmail"}</h6>
+                                                        <h6 class="card-title">{i18n.t("email-type-hotmail")}</h6>
                                                         <p class="card-text">
-                                                            {available_emails.hotmail.data}
+                                                            {format_number(available_emails.hotmail.data)}
                                                         </p>
                                                     </div>
                                                 </div>
@@ -435,9 +754,9 @@ fn app() -> Html {
                                             <div class="col-md-4">
                                                 <div class="card">
                                                     <div class="card-body">
-                                                        <h6 class="card-title">{"Allocated"}</h6>
+                                                        <h6 class="card-title">{i18n.t("stock-allocated")}</h6>
                                                         <p class="card-text">
-                                                            {available_emails.allocated.data}
+                                                            {format_number(available_emails.allocated.data)}
                                                         </p>
                                                     </div>
                                                 </div>
@@ -445,76 +764,88 @@ fn app() -> Html {
                                         </div>
                                     </div>
                                     <div class="mb-3">
-                                        <label for="email-type" class="form-label">{"Select Email Type"}</label>
+                                        <label for="email-type" class="form-label">{i18n.t("email-type-label")}</label>
                                         <select id="email-type" class="form-select" onchange={onchange}>
-                                            <option value="random" selected=true>{"Random"}</option>
-                                            <option value="outlook">{"Outlook"}</option>
-                                            <option value="hotmail">{"Hotmail"}</option>
+                                            <option value="random" selected={*email_type == "random"}>{i18n.t("email-type-random")}</option>
+                                            <option value="outlook" selected={*email_type == "outlook"}>{i18n.t("email-type-outlook")}</option>
+                                            <option value="hotmail" selected={*email_type == "hotmail"}>{i18n.t("email-type-hotmail")}</option>
                                         </select>
                                     </div>
                                     <div id="recaptcha-container" class="mb-4"></div>
                                     <div class="d-flex justify-content-between mb-4">
-                                        <button class="btn btn-primary btn-lg flex-grow-1 me-2" onclick={onclick} disabled={*loading}>
-                                            {if *loading { "Generating..." } else { "Generate Email" }}
+                                        <button class="btn btn-primary btn-lg flex-grow-1 me-2" onclick={onclick} disabled={*loading || single_cooldown.is_active()}>
+                                            {if *loading {
+                                                i18n.t("generating")
+                                            } else if single_cooldown.is_active() {
+                                                i18n.t_args("retry-in", [("remaining", format_remaining(single_cooldown.remaining_secs()).into())])
+                                            } else {
+                                                i18n.t("generate-button")
+                                            }}
                                         </button>
-                                        <button class="btn btn-primary btn-lg" onclick={bulk_onclick} disabled={*bulk_loading}>
-                                            {if *bulk_loading { 
-                                                html! { <span class="spinner-border spinner-border-sm" role="status" aria-hidden="true"></span> }
-                                            } else { 
+                                        if *loading {
+                                            <button class="btn btn-outline-secondary btn-lg me-2" onclick={cancel_single}>
+                                                {i18n.t("cancel")}
+                                            </button>
+                                        }
+                                        <button
+                                            class="btn btn-primary btn-lg"
+                                            onclick={bulk_onclick}
+                                            disabled={*bulk_loading || bulk_cooldown.is_active()}
+                                            aria-label={bulk_loading.then(|| bulk_progress_status.clone())}
+                                        >
+                                            {if *bulk_loading {
+                                                html! {
+                                                    <>
+                                                        <span class="spinner-border spinner-border-sm me-1" aria-hidden="true"></span>
+                                                        {i18n.t_args("bulk-progress", [("received", bulk_progress.0.into()), ("total", bulk_progress.1.into())])}
+                                                    </>
+                                                }
+                                            } else if bulk_cooldown.is_active() {
+                                                html! { i18n.t_args("bulk-retry-in", [("remaining", format_remaining(bulk_cooldown.remaining_secs()).into())]) }
+                                            } else {
                                                 html! {
                                                     <>
-                                                        <img src="https://www.svgrepo.com/show/24993/lightning-bolt-black-shape.svg" 
-                                                             alt="Lightning bolt" 
-                                                             style="width: 20px; height: 20px; margin-right: 5px; filter: invert(1);" />
-                                                        {"Bulk (10)"}
+                                                        <svg class="bulk-icon" viewBox="0 0 24 24" aria-hidden="true" focusable="false">
+                                                            <path fill="currentColor" d="M13 2 4 14h6l-1 8 9-12h-6l1-8z" />
+                                                        </svg>
+                                                        {i18n.t_args("bulk-button", [("count", BULK_SIZE.into())])}
                                                     </>
                                                 }
                                             }}
                                         </button>
+                                        if *bulk_loading {
+                                            <button class="btn btn-outline-secondary btn-lg ms-2" onclick={cancel_bulk}>
+                                                {i18n.t("cancel")}
+                                            </button>
+                                        }
+                                    </div>
+                                    if *bulk_loading {
+                                        <div
+                                            class="progress mb-4"
+                                            role="progressbar"
+                                            aria-label={i18n.t("bulk-progress-label")}
+                                            aria-valuenow={bulk_progress.0.to_string()}
+                                            aria-valuemin="0"
+                                            aria-valuemax={bulk_progress.1.to_string()}
+                                        >
+                                            <div
+                                                class="progress-bar progress-bar-striped progress-bar-animated"
+                                                style={format!("width: {}%;", bulk_progress.0 * 100 / bulk_progress.1.max(1))}
+                                            ></div>
+                                        </div>
+                                    }
+                                    <div class="visually-hidden" role="status" aria-live="polite" aria-atomic="true">
+                                        {(*status).clone()}
                                     </div>
                                     {
                                         if !email_data.email.is_empty() {
                                             html! {
                                                 <div class="mt-4">
-                                                    <h3 class="h5 mb-3">{"Your Temporary Email"}</h3>
-                                                    if email_data.email.starts_with("Error:") {
-                                                        <div class="alert alert-danger" role="alert">
-                                                            {email_data.email.clone()}
-                                                        </div>
-                                                    } else {
-                                                        <div class="mb-3">
-                                                            <button class="btn btn-secondary mb-2" onclick={toggle_view}>
-                                                                {if *show_credentials { "Show RefreshToken:ClientID" } else { "Show Username:Password" }}
-                                                            </button>
-                                                            <div class="input-group">
-                                                                <input 
-                                                                    type="text" 
-                                                                    class="form-control" 
-                                                                    value={
-                                                                        if *show_credentials {
-                                                                            format!("{}:{}", email_data.email, email_data.password)
-                                                                        } else {
-                                                                            format!("{}:{}", email_data.refresh_token, email_data.client_id)
-                                                                        }
-                                                                    } 
-                                                                    readonly=true 
-                                                                />
-                                                                <button class="btn btn-outline-secondary" onclick={Callback::from(move |_| {
-                                                                    let window = web_sys::window().unwrap();
-                                                                    let navigator = window.navigator();
-                                                                    let clipboard = navigator.clipboard();
-                                                                    let text = if *show_credentials {
-                                                                        format!("{}:{}", email_data.email, email_data.password)
-                                                                    } else {
-                                                                        format!("{}:{}", email_data.refresh_token, email_data.client_id)
-                                                                    };
-                                                                    let _ = clipboard.write_text(&text);
-                                                                })}>
-                                                                    {"Copy"}
-                                                                </button>
-                                                            </div>
-                                                        </div>
-                                                    }
+                                                    <h3 class="h5 mb-3" tabindex="-1" ref={single_heading}>{i18n.t("single-result-heading")}</h3>
+                                                    <div class="mb-3">
+                                                        <CredentialCard key={single_email.clone()} account={(*email_data).clone()} />
+                                                        <ReportButton email={single_email} on_replacement={on_single_replacement} />
+                                                    </div>
                                                 </div>
                                             }
                                         } else {
@@ -522,79 +853,43 @@ fn app() -> Html {
                                         }
                                     }
                                     {
-                                        if !error_message.is_empty() {
+                                        if !bulk_emails.emails.is_empty() {
                                             html! {
-                                                <div class="alert alert-danger mt-4" role="alert">
-                                                    {(*error_message).clone()}
+                                                <div class="mt-4" aria-busy={bulk_loading.to_string()}>
+                                                    <h3 class="h5 mb-3" tabindex="-1" ref={bulk_heading}>{i18n.t("bulk-result-heading")}</h3>
+                                                    <BulkTable emails={bulk_emails.emails.clone()} on_replacement={on_bulk_replacement} />
                                                 </div>
                                             }
                                         } else {
                                             html! {}
                                         }
                                     }
-                                    {
-                                        if !bulk_emails.is_empty() {
-                                            html! {
-                                                <div class="mt-4">
-                                                    <h3 class="h5 mb-3">{"Your Bulk Temporary Emails"}</h3>
-                                                    <button class="btn btn-secondary mb-2" onclick={toggle_bulk_view}>
-                                                        {if *show_bulk_credentials { "Show RefreshToken:ClientID" } else { "Show Username:Password" }}
-                                                    </button>
-                                                    <ul class="list-group">
-                                                        {bulk_emails.iter().map(|email_data| {
-                                                            html! {
-                                                                <li class="list-group-item">
-                                                                    {if *show_bulk_credentials {
-                                                                        format!("{}:{}", email_data.email, email_data.password)
-                                                                    } else {
-                                                                        format!("{}:{}", email_data.refresh_token, email_data.client_id)
-                                                                    }}
-                                                                </li>
-                                                            }
-                                                        }).collect::<Html>()}
-                                                    </ul>
-                                                    <button class="btn btn-outline-secondary mt-2" onclick={Callback::from(move |_| {
-                                                        let window = web_sys::window().unwrap();
-                                                        let navigator = window.navigator();
-                                                        let clipboard = navigator.clipboard();
-                                                        let text = bulk_emails.iter().map(|email_data| {
-                                                            if *show_bulk_credentials {
-                                                                format!("{}:{}", email_data.email, email_data.password)
-                                                            } else {
-                                                                format!("{}:{}", email_data.refresh_token, email_data.client_id)
-                                                            }
-                                                        }).collect::<Vec<String>>().join("\n");
-                                                        let _ = clipboard.write_text(&text);
-                                                    })}>
-                                                        {"Copy All"}
-                                                    </button>
-                                                </div>
-                                            }
-                                        } else {
-                                            html! {}
-                                        }
+                                    if has_results {
+                                        <ClipboardSettings />
                                     }
                                 </div>
                                 <div class="card-footer text-center">
-                                    <button 
-                                        class="btn btn-link text-muted" 
+                                    <button
+                                        class="btn btn-link text-muted"
+                                        aria-expanded={info_expanded.to_string()}
+                                        aria-controls="technical-details"
                                         onclick={toggle_info}
                                     >
-                                        {if *info_expanded { "Hide Technical Details" } else { "How Does It Work?" }}
+                                        {i18n.t(if *info_expanded { "info-hide" } else { "info-show" })}
                                     </button>
                                     {
                                         if *info_expanded {
                                             html! {
-                                                <div class="mt-4 text-start">
-                                                    <h4 class="mb-3">{"How It Works: Key Features"}</h4>
-                                                    <p>{"This system uses some awesome techniques to generate temporary email addresses, including:"}</p>
+                                                <div id="technical-details" class="mt-4 text-start">
+                                                    <h4 class="mb-3">{i18n.t("info-heading")}</h4>
+                                                    <p>{i18n.t("info-intro")}</p>
                                                     <ul>
-                                                        <li>{"Custom FunCaptcha bypass, with 2000 raw recognitions/day provided graciously by "}<a href="https://nopecha.com/pricing" target="_blank" rel="noopener noreferrer">{"NopeCHA!"}</a></li>
-                                                        <li>{"Rotating residential proxies"}</li>
-                                                        <li>{"Low-level API interactions for Microsoft Auth/Funcaptcha - this stuff's "}<strong>{"HARD!"}</strong></li>
+                                                        <li>{i18n.t("info-captcha")}{" "}<a href="https://nopecha.com/pricing" target="_blank" rel="noopener noreferrer">{"NopeCHA!"}</a></li>
+                                                        <li>{i18n.t("info-proxies")}</li>
+                                                        <li>{i18n.t("info-api")}{" "}<strong>{i18n.t("info-api-emphasis")}</strong></li>
                                                     </ul>
-                                                    <p>{"This project mainly serves as a practical exercise in maintaining a long-term, complex reverse engineering software project. As long as the service is still running more than a few months from it's creation, I consider the challenge a success."}</p>
-                                                    <p>{"Note: If you see zero allocated accounts, don't worry! I'm definitely aware of it and actively working on patching any new changes. I take a lot of pride in keeping this service running and will be sure to have it up and running by the next day."}</p>
+                                                    <p>{i18n.t("info-purpose")}</p>
+                                                    <p>{i18n.t("info-note")}</p>
                                                 </div>
                                             }
                                         } else {
@@ -604,12 +899,16 @@ fn app() -> Html {
                                 </div>
                                 <div class="card-footer text-center text-muted">
                                     <small>
-                                        {"For more cool projects, check out my "}
+                                        {i18n.t("footer-projects")}{" "}
                                         <a href="https://github.com/AmirAgassi" target="_blank" rel="noopener noreferrer" class="text-muted">
                                             {"GitHub"}
                                         </a>
-                                        {"!"}
+                                        {i18n.t("footer-projects-end")}
                                     </small>
+                                    <div class="d-flex justify-content-center align-items-center gap-2 mt-2">
+                                        <ThemePicker />
+                                        <LanguagePicker />
+                                    </div>
                                 </div>
                             </div>
                         </div>
@@ -620,6 +919,108 @@ fn app() -> Html {
     }
 }
 
+// offline shell, see sw.js
+fn register_service_worker() {
+    let navigator = web_sys::window().expect("no global `window` exists").navigator();
+    if js_sys::Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        let _ = navigator.service_worker().register("/sw.js");
+    }
+}
+
 fn main() {
+    register_service_worker();
     yew::start_app::<App>();
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_account_line() {
+        assert_eq!(
+            EmailData::from_line("a@outlook.com:pass:token:client"),
+            Some(EmailData {
+                email: "a@outlook.com".to_string(),
+                password: "pass".to_string(),
+                refresh_token: "token".to_string(),
+                client_id: "client".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_lines_without_four_fields() {
+        assert_eq!(EmailData::from_line("a@outlook.com:pass:token"), None);
+        assert_eq!(EmailData::from_line("a@outlook.com:pa:ss:token:client"), None);
+        assert_eq!(EmailData::from_line("Error: out of stock"), None);
+        assert_eq!(EmailData::from_line(""), None);
+    }
+
+    fn account(email: &str) -> EmailData {
+        EmailData {
+            email: email.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn listed(state: &BulkEmails) -> Vec<&str> {
+        state.emails.iter().map(|row| row.email.as_str()).collect()
+    }
+
+    #[test]
+    fn skips_accounts_already_listed() {
+        let state = Rc::new(BulkEmails::default())
+            .reduce(BulkAction::Push(account("a")))
+            .reduce(BulkAction::Push(account("b")))
+            .reduce(BulkAction::Push(account("a")));
+        assert_eq!(listed(&state), ["a", "b"]);
+    }
+
+    #[test]
+    fn replaces_without_duplicating_rows() {
+        let state = Rc::new(BulkEmails::default())
+            .reduce(BulkAction::Push(account("a")))
+            .reduce(BulkAction::Push(account("b")))
+            .reduce(BulkAction::Replace { email: "a".to_string(), replacement: account("c") });
+        assert_eq!(listed(&state), ["c", "b"]);
+
+        let state = state.reduce(BulkAction::Replace { email: "c".to_string(), replacement: account("b") });
+        assert_eq!(listed(&state), ["b"]);
+    }
+
+    #[test]
+    fn reports_server_errors_after_partial_results() {
+        let outcome = bulk_outcome(Ok(()), 3, Some(FetchError::RateLimited(30)));
+        assert!(matches!(outcome, BulkOutcome::Partial(FetchError::RateLimited(30))));
+        assert_eq!(outcome.cooldown(), Some(30));
+
+        let outcome = bulk_outcome(Ok(()), 3, Some(FetchError::InvalidResponse));
+        assert!(matches!(outcome, BulkOutcome::Partial(FetchError::InvalidResponse)));
+        assert_eq!(outcome.cooldown(), None);
+    }
+
+    #[test]
+    fn fails_when_nothing_arrived() {
+        let outcome = bulk_outcome(Ok(()), 0, Some(FetchError::RateLimited(30)));
+        assert!(matches!(outcome, BulkOutcome::Failed(FetchError::RateLimited(30))));
+        assert_eq!(outcome.cooldown(), Some(30));
+
+        assert!(matches!(bulk_outcome(Ok(()), 0, None), BulkOutcome::Empty));
+        assert!(matches!(
+            bulk_outcome(Err(FetchError::Timeout), 0, None),
+            BulkOutcome::Failed(FetchError::Timeout)
+        ));
+    }
+
+    #[test]
+    fn keeps_partial_results_when_the_stream_stops() {
+        assert!(matches!(bulk_outcome(Ok(()), 10, None), BulkOutcome::Done));
+        assert!(matches!(bulk_outcome(Err(FetchError::Timeout), 4, None), BulkOutcome::TimedOut));
+        assert!(matches!(
+            bulk_outcome(Err(FetchError::Network("reset".to_string())), 4, None),
+            BulkOutcome::ConnectionLost
+        ));
+        assert!(matches!(bulk_outcome(Err(FetchError::Cancelled), 4, None), BulkOutcome::Cancelled));
+    }
+}
@@ -0,0 +1,136 @@
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlSelectElement;
+use serde::{Deserialize, Serialize};
+
+use crate::EmailData;
+use crate::api::ApiError;
+use crate::fetch::{self, use_abort_scope, Abort, FetchError, API_TIMEOUT_MS};
+use crate::i18n::{t, use_i18n};
+use crate::toast::use_toaster;
+
+// reason codes understood by /report_account, with their label message ids
+const REPORT_REASONS: [(&str, &str); 4] = [
+    ("locked", "report-reason-locked"),
+    ("wrong_password", "report-reason-wrong-password"),
+    ("token_invalid", "report-reason-token-invalid"),
+    ("other", "report-reason-other"),
+];
+
+#[derive(Serialize)]
+struct ReportRequest<'a> {
+    email: &'a str,
+    reason: &'a str,
+}
+
+// server answer - either a replacement account line or the reason there is none
+#[derive(Deserialize)]
+struct ReportResponse {
+    #[serde(default)]
+    replacement: Option<String>,
+    #[serde(default)]
+    message: String,
+}
+
+async fn report_account(email: &str, reason: &str, abort: &Abort) -> Result<ReportResponse, FetchError> {
+    let response = fetch::post_json(
+        "https://burneroutlook-api.vercel.app/report_account",
+        &ReportRequest { email, reason },
+        abort,
+    )
+    .await?;
+
+    if !response.ok() {
+        let text = fetch::text(&response, abort).await.unwrap_or_default();
+        return Err(match ApiError::parse(&text) {
+            Some(error) => error.into_fetch_error(),
+            None => FetchError::Network(t("report-failed")),
+        });
+    }
+
+    fetch::json::<ReportResponse>(&response, abort).await
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReportButtonProps {
+    pub email: String,
+    // called with the replacement account when the server issues one
+    pub on_replacement: Callback<EmailData>,
+}
+
+#[function_component(ReportButton)]
+pub fn report_button(props: &ReportButtonProps) -> Html {
+    let reason = use_state(|| REPORT_REASONS[0].0.to_string());
+    let loading = use_state(|| false);
+    let toaster = use_toaster();
+    let i18n = use_i18n();
+    let scope = use_abort_scope();
+
+    let onchange = {
+        let reason = reason.clone();
+        Callback::from(move |e: Event| {
+            if let Some(target) = e.target_dyn_into::<HtmlSelectElement>() {
+                reason.set(target.value());
+            }
+        })
+    };
+
+    let onclick = {
+        let reason = reason.clone();
+        let loading = loading.clone();
+        let toaster = toaster.clone();
+        let scope = scope.clone();
+        let email = props.email.clone();
+        let on_replacement = props.on_replacement.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let reason = reason.clone();
+            let loading = loading.clone();
+            let toaster = toaster.clone();
+            let email = email.clone();
+            let on_replacement = on_replacement.clone();
+            let i18n = i18n.clone();
+            loading.set(true);
+            let abort = scope.start(API_TIMEOUT_MS);
+            spawn_local(async move {
+                match report_account(&email, &reason, &abort).await {
+                    Ok(response) => {
+                        match response.replacement.as_deref().and_then(EmailData::from_line) {
+                            Some(data) => {
+                                toaster.success(i18n.t_args(
+                                    "report-replaced",
+                                    [("email", email.as_str().into()), ("replacement", data.email.as_str().into())],
+                                ));
+                                on_replacement.emit(data);
+                            }
+                            None if response.message.is_empty() => {
+                                toaster.info(i18n.t("report-no-replacement"));
+                            }
+                            None => toaster.info(response.message),
+                        }
+                    }
+                    Err(FetchError::Unmounted) => return,
+                    Err(e) => toaster.error(e.message()),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="mt-2">
+            <div class="input-group input-group-sm">
+                <select class="form-select" aria-label={i18n.t("report-reason-label")} onchange={onchange}>
+                    {REPORT_REASONS.iter().map(|(code, label)| {
+                        html! {
+                            <option value={*code} selected={*reason == *code}>{i18n.t(label)}</option>
+                        }
+                    }).collect::<Html>()}
+                </select>
+                <button class="btn btn-outline-danger" onclick={onclick} disabled={*loading}>
+                    {i18n.t(if *loading { "reporting" } else { "report" })}
+                </button>
+            </div>
+        </div>
+    }
+}
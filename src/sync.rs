@@ -0,0 +1,141 @@
+use yew::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{BroadcastChannel, MessageEvent};
+use gloo_timers::callback::Interval;
+use serde::{Deserialize, Serialize};
+
+use crate::AvailableEmails;
+use crate::local_storage;
+
+const CHANNEL_NAME: &str = "tempinbox-sync";
+
+// the leader renews its lease every heartbeat; others take over once it lapses
+const LEADER_KEY: &str = "sync_leader";
+const LEASE_MS: f64 = 5000.0;
+const HEARTBEAT_MS: u32 = 2000;
+
+// state shared between open tabs
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SyncMessage {
+    Cooldown { endpoint: String, until: f64 },
+    Stock { stock: AvailableEmails },
+}
+
+// what goes over the channel, tagged with the sending tab
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    from: String,
+    #[serde(flatten)]
+    message: SyncMessage,
+}
+
+thread_local! {
+    static TAB_ID: String = format!("{:x}", (js_sys::Math::random() * 1e16) as u64);
+}
+
+pub fn broadcast(message: &SyncMessage) {
+    let envelope = Envelope {
+        from: TAB_ID.with(|id| id.clone()),
+        message: message.clone(),
+    };
+    let Ok(text) = serde_json::to_string(&envelope) else {
+        return;
+    };
+    if let Ok(channel) = BroadcastChannel::new(CHANNEL_NAME) {
+        let _ = channel.post_message(&JsValue::from_str(&text));
+        channel.close();
+    }
+}
+
+// runs on_message for everything other tabs broadcast while the component is mounted
+pub fn use_sync(on_message: impl Fn(SyncMessage) + 'static) {
+    use_effect_with_deps(
+        move |_| {
+            let channel = BroadcastChannel::new(CHANNEL_NAME).ok();
+            let listener = Closure::wrap(Box::new(move |event: MessageEvent| {
+                let Some(envelope) = event
+                    .data()
+                    .as_string()
+                    .and_then(|text| serde_json::from_str::<Envelope>(&text).ok())
+                else {
+                    return;
+                };
+                // broadcast posts on its own channel object, so this tab hears itself too
+                if TAB_ID.with(|id| *id != envelope.from) {
+                    on_message(envelope.message);
+                }
+            }) as Box<dyn FnMut(MessageEvent)>);
+            if let Some(channel) = &channel {
+                channel.set_onmessage(Some(listener.as_ref().unchecked_ref()));
+            }
+
+            move || {
+                if let Some(channel) = channel {
+                    channel.set_onmessage(None);
+                    channel.close();
+                }
+                drop(listener);
+            }
+        },
+        (),
+    );
+}
+
+// lease is stored as "<tab id>|<expiry ms>"
+fn current_leader() -> Option<(String, f64)> {
+    let lease = local_storage()?.get_item(LEADER_KEY).ok().flatten()?;
+    let (tab, expires) = lease.split_once('|')?;
+    Some((tab.to_string(), expires.parse().ok()?))
+}
+
+fn try_claim_leadership() -> bool {
+    let Some(storage) = local_storage() else {
+        // nothing to coordinate through, act alone
+        return true;
+    };
+    let tab_id = TAB_ID.with(|id| id.clone());
+    let now = js_sys::Date::now();
+    let free = match current_leader() {
+        Some((tab, expires)) => tab == tab_id || expires < now,
+        None => true,
+    };
+    if !free {
+        return false;
+    }
+    let _ = storage.set_item(LEADER_KEY, &format!("{}|{}", tab_id, now + LEASE_MS));
+    // another tab may have written in between, whoever's lease stuck wins
+    matches!(current_leader(), Some((tab, _)) if tab == tab_id)
+}
+
+fn resign_leadership() {
+    let tab_id = TAB_ID.with(|id| id.clone());
+    if matches!(current_leader(), Some((tab, _)) if tab == tab_id) {
+        if let Some(storage) = local_storage() {
+            let _ = storage.remove_item(LEADER_KEY);
+        }
+    }
+}
+
+// true in exactly one open tab, which does the polling for everyone
+pub fn use_leader() -> bool {
+    let leader = use_state_eq(|| false);
+    {
+        let leader = leader.clone();
+        use_effect_with_deps(
+            move |_| {
+                leader.set(try_claim_leadership());
+                let heartbeat = Interval::new(HEARTBEAT_MS, move || {
+                    leader.set(try_claim_leadership());
+                });
+                move || {
+                    drop(heartbeat);
+                    resign_leadership();
+                }
+            },
+            (),
+        );
+    }
+    *leader
+}
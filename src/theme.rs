@@ -0,0 +1,182 @@
+use yew::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::MediaQueryList;
+
+use crate::i18n::use_i18n;
+use crate::local_storage;
+
+// also read by the pre-paint script in index.html
+const THEME_KEY: &str = "theme";
+const DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    System,
+}
+
+const THEME_MODES: [ThemeMode; 3] = [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System];
+
+impl ThemeMode {
+    fn value(self) -> &'static str {
+        match self {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
+        }
+    }
+
+    fn message_id(self) -> &'static str {
+        match self {
+            ThemeMode::Light => "theme-light",
+            ThemeMode::Dark => "theme-dark",
+            ThemeMode::System => "theme-system",
+        }
+    }
+
+    fn stored() -> Self {
+        let value = local_storage().and_then(|s| s.get_item(THEME_KEY).ok().flatten());
+        THEME_MODES
+            .into_iter()
+            .find(|mode| Some(mode.value()) == value.as_deref())
+            .unwrap_or(ThemeMode::System)
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Theme {
+    pub mode: ThemeMode,
+    // the mode with System resolved against the OS preference
+    pub dark: bool,
+    set_mode: Callback<ThemeMode>,
+}
+
+impl Theme {
+    // "light" or "dark", as bootstrap and recaptcha expect it
+    pub fn name(&self) -> &'static str {
+        if self.dark {
+            "dark"
+        } else {
+            "light"
+        }
+    }
+
+    pub fn set_mode(&self, mode: ThemeMode) {
+        self.set_mode.emit(mode);
+    }
+}
+
+pub fn use_theme() -> Theme {
+    use_context::<Theme>().expect("use_theme called outside of ThemeProvider")
+}
+
+fn dark_query() -> Option<MediaQueryList> {
+    web_sys::window()?.match_media(DARK_QUERY).ok().flatten()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ThemeProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+#[function_component(ThemeProvider)]
+pub fn theme_provider(props: &ThemeProviderProps) -> Html {
+    let mode = use_state_eq(ThemeMode::stored);
+    let system_dark = use_state_eq(|| dark_query().map(|q| q.matches()).unwrap_or(false));
+
+    // follow the OS setting while in system mode
+    {
+        let system_dark = system_dark.clone();
+        use_effect_with_deps(
+            move |_| {
+                let query = dark_query();
+                let listener = Closure::wrap(Box::new(move || {
+                    system_dark.set(dark_query().map(|q| q.matches()).unwrap_or(false));
+                }) as Box<dyn FnMut()>);
+                if let Some(query) = &query {
+                    query.set_onchange(Some(listener.as_ref().unchecked_ref()));
+                }
+                move || {
+                    if let Some(query) = query {
+                        query.set_onchange(None);
+                    }
+                    drop(listener);
+                }
+            },
+            (),
+        );
+    }
+
+    let dark = match *mode {
+        ThemeMode::Light => false,
+        ThemeMode::Dark => true,
+        ThemeMode::System => *system_dark,
+    };
+
+    // bootstrap and styles.css key their colour variables off data-bs-theme
+    use_effect_with_deps(
+        |dark| {
+            if let Some(root) = web_sys::window()
+                .and_then(|w| w.document())
+                .and_then(|d| d.document_element())
+            {
+                let _ = root.set_attribute("data-bs-theme", if *dark { "dark" } else { "light" });
+            }
+            || ()
+        },
+        dark,
+    );
+
+    let set_mode = {
+        let mode = mode.clone();
+        Callback::from(move |next: ThemeMode| {
+            if let Some(storage) = local_storage() {
+                let _ = storage.set_item(THEME_KEY, next.value());
+            }
+            mode.set(next);
+        })
+    };
+
+    let theme = Theme {
+        mode: *mode,
+        dark,
+        set_mode,
+    };
+
+    html! {
+        <ContextProvider<Theme> context={theme}>
+            { for props.children.iter() }
+        </ContextProvider<Theme>>
+    }
+}
+
+#[function_component(ThemePicker)]
+pub fn theme_picker() -> Html {
+    let theme = use_theme();
+    let i18n = use_i18n();
+
+    html! {
+        <div class="btn-group btn-group-sm" role="group" aria-label={i18n.t("theme-label")}>
+            {THEME_MODES.iter().map(|mode| {
+                let onclick = {
+                    let theme = theme.clone();
+                    let mode = *mode;
+                    Callback::from(move |_| theme.set_mode(mode))
+                };
+                html! {
+                    <button
+                        type="button"
+                        class={classes!("btn", if theme.mode == *mode { "btn-secondary" } else { "btn-outline-secondary" })}
+                        aria-pressed={(theme.mode == *mode).to_string()}
+                        onclick={onclick}
+                    >
+                        {i18n.t(mode.message_id())}
+                    </button>
+                }
+            }).collect::<Html>()}
+        </div>
+    }
+}
@@ -0,0 +1,171 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use yew::prelude::*;
+use gloo_timers::callback::Timeout;
+
+use crate::i18n::use_i18n;
+
+// most toasts on screen at once - the oldest is dropped first
+const MAX_TOASTS: usize = 4;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    fn class(self) -> &'static str {
+        match self {
+            ToastLevel::Info => "text-bg-primary",
+            ToastLevel::Success => "text-bg-success",
+            ToastLevel::Warning => "text-bg-warning",
+            ToastLevel::Error => "text-bg-danger",
+        }
+    }
+
+    // problems stay up longer so there's time to read them
+    fn duration_ms(self) -> u32 {
+        match self {
+            ToastLevel::Info | ToastLevel::Success => 4000,
+            ToastLevel::Warning => 6000,
+            ToastLevel::Error => 8000,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct Toast {
+    id: u32,
+    level: ToastLevel,
+    message: String,
+}
+
+enum ToastAction {
+    Push(Toast),
+    Dismiss(u32),
+}
+
+#[derive(Default, PartialEq)]
+struct ToastState {
+    toasts: Vec<Toast>,
+}
+
+impl Reducible for ToastState {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: ToastAction) -> Rc<Self> {
+        let mut toasts = self.toasts.clone();
+        match action {
+            ToastAction::Push(toast) => {
+                toasts.push(toast);
+                if toasts.len() > MAX_TOASTS {
+                    toasts.drain(..toasts.len() - MAX_TOASTS);
+                }
+            }
+            ToastAction::Dismiss(id) => toasts.retain(|toast| toast.id != id),
+        }
+        Rc::new(ToastState { toasts })
+    }
+}
+
+// handle for raising toasts from anywhere under ToastProvider
+#[derive(Clone, PartialEq)]
+pub struct Toaster {
+    push: Callback<(ToastLevel, String)>,
+}
+
+impl Toaster {
+    pub fn show(&self, level: ToastLevel, message: impl Into<String>) {
+        self.push.emit((level, message.into()));
+    }
+
+    pub fn info(&self, message: impl Into<String>) {
+        self.show(ToastLevel::Info, message);
+    }
+
+    pub fn success(&self, message: impl Into<String>) {
+        self.show(ToastLevel::Success, message);
+    }
+
+    pub fn warning(&self, message: impl Into<String>) {
+        self.show(ToastLevel::Warning, message);
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        self.show(ToastLevel::Error, message);
+    }
+}
+
+pub fn use_toaster() -> Toaster {
+    use_context::<Toaster>().expect("use_toaster called outside of ToastProvider")
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ToastProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+#[function_component(ToastProvider)]
+pub fn toast_provider(props: &ToastProviderProps) -> Html {
+    let state = use_reducer(ToastState::default);
+    let i18n = use_i18n();
+
+    // built once so the context value never changes and consumers don't re-render
+    let toaster = {
+        let dispatcher = state.dispatcher();
+        use_state(move || {
+            let next_id = Cell::new(0u32);
+            Toaster {
+                push: Callback::from(move |(level, message): (ToastLevel, String)| {
+                    let id = next_id.get();
+                    next_id.set(id.wrapping_add(1));
+                    dispatcher.dispatch(ToastAction::Push(Toast { id, level, message }));
+
+                    let dispatcher = dispatcher.clone();
+                    Timeout::new(level.duration_ms(), move || {
+                        dispatcher.dispatch(ToastAction::Dismiss(id));
+                    })
+                    .forget();
+                }),
+            }
+        })
+    };
+
+    html! {
+        <ContextProvider<Toaster> context={(*toaster).clone()}>
+            { for props.children.iter() }
+            // the container is always rendered so screen readers pick up new toasts
+            <div class="toast-container position-fixed top-0 end-0 p-3" aria-live="polite" aria-atomic="false">
+                {state.toasts.iter().map(|toast| {
+                    let dismiss = {
+                        let state = state.clone();
+                        let id = toast.id;
+                        Callback::from(move |_| state.dispatch(ToastAction::Dismiss(id)))
+                    };
+                    html! {
+                        <div
+                            key={toast.id}
+                            class={classes!("toast", "show", "align-items-center", "border-0", toast.level.class())}
+                            role={if toast.level == ToastLevel::Error { "alert" } else { "status" }}
+                            aria-atomic="true"
+                        >
+                            <div class="d-flex">
+                                <div class="toast-body">{toast.message.clone()}</div>
+                                <button
+                                    type="button"
+                                    class={classes!("btn-close", "me-2", "m-auto", (toast.level != ToastLevel::Warning).then_some("btn-close-white"))}
+                                    aria-label={i18n.t("close")}
+                                    onclick={dismiss}
+                                ></button>
+                            </div>
+                        </div>
+                    }
+                }).collect::<Html>()}
+            </div>
+        </ContextProvider<Toaster>>
+    }
+}
@@ -0,0 +1,90 @@
+// Offline shell for tempinbox.es.
+// Trunk fingerprints the js/wasm/css file names on every build, so they can't
+// be precached by name - they're cached in RUNTIME the first time the page
+// loads them, and dropped once a fresh index.html stops referencing them.
+const CACHE = "tempinbox-shell-v2";
+const RUNTIME = "tempinbox-runtime-v1";
+const CACHES = [CACHE, RUNTIME];
+const SHELL = ["/", "/index.html", "/manifest.webmanifest", "/rust.svg"];
+
+// removes assets from older builds that the current page no longer links to
+function pruneRuntime(html) {
+  return caches.open(RUNTIME).then((cache) =>
+    cache.keys().then((requests) =>
+      Promise.all(
+        requests
+          .filter((request) => !html.includes(new URL(request.url).pathname))
+          .map((request) => cache.delete(request))
+      )
+    )
+  );
+}
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches.open(CACHE).then((cache) => cache.addAll(SHELL)).then(() => self.skipWaiting())
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) => Promise.all(keys.filter((key) => !CACHES.includes(key)).map((key) => caches.delete(key))))
+      .then(() => self.clients.claim())
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+
+  // api calls and third-party scripts (recaptcha, bootstrap) always go to the network
+  if (request.method !== "GET" || url.origin !== self.location.origin) {
+    return;
+  }
+
+  // pages: network first so a new deploy is picked up, cached shell when offline
+  if (request.mode === "navigate") {
+    event.respondWith(
+      fetch(request)
+        .then((response) => {
+          // an error page from a bad deploy or outage must not become the offline shell
+          if (response.ok) {
+            const copy = response.clone();
+            const html = response.clone().text();
+            event.waitUntil(
+              caches
+                .open(CACHE)
+                .then((cache) => cache.put("/index.html", copy))
+                .then(() => html)
+                .then(pruneRuntime)
+            );
+          }
+          return response;
+        })
+        .catch(() => caches.match("/index.html"))
+    );
+    return;
+  }
+
+  // assets: serve from cache, refresh in the background
+  event.respondWith(
+    caches.match(request).then((cached) => {
+      const network = fetch(request).then((response) => {
+        if (response.ok) {
+          const copy = response.clone();
+          const name = SHELL.includes(url.pathname) ? CACHE : RUNTIME;
+          caches.open(name).then((cache) => cache.put(request, copy));
+        }
+        return response;
+      });
+      if (cached) {
+        // offline refreshes just keep the cached copy
+        network.catch(() => {});
+        return cached;
+      }
+      return network;
+    })
+  );
+});